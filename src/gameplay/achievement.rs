@@ -0,0 +1,175 @@
+use crate::asset_tracking::LoadResource;
+use crate::gameplay::enemy::EnemyDeathEvent;
+use crate::gameplay::player::{Player, PlayerHurt, Xp};
+use crate::gameplay::replay::Replay;
+use crate::hud::timer::SurvivalTimer;
+use crate::profile::storage;
+use crate::screen::Screen;
+use crate::{AppSystems, PausableSystems};
+use bevy::prelude::*;
+use ron_asset_manager::prelude::RonAsset;
+use serde::Deserialize;
+
+const ACHIEVEMENTS_FILE: &str = "achievements.ron";
+
+pub fn plugin(app: &mut App) {
+    app.load_resource::<AchievementAssets>("achievements.ron");
+    app.insert_resource(UnlockedAchievements(
+        storage::load_or_back_up(ACHIEVEMENTS_FILE).unwrap_or_default(),
+    ));
+    app.add_observer(count_kill);
+    app.add_observer(reset_no_damage);
+    app.add_systems(OnEnter(Screen::Gameplay(false)), reset_counters);
+    app.add_systems(
+        FixedUpdate,
+        count_time
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems)
+            .run_if(in_state(Screen::Gameplay(false))),
+    );
+}
+
+#[derive(Resource, Asset, RonAsset, TypePath, Deserialize, Debug, Clone)]
+pub struct AchievementAssets {
+    pub achievements: Vec<Achievement>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Achievement {
+    /// Key saved once unlocked, names can change freely.
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub condition: Condition,
+}
+
+/// Reached within a single run.
+#[derive(Deserialize, Debug, Copy, Clone)]
+pub enum Condition {
+    Kills(u32),
+    /// In seconds.
+    TimeSurvived(f32),
+    LevelReached(u32),
+    BossKills(u32),
+    /// Consecutive minutes without getting hurt.
+    NoDamageMinutes(f32),
+}
+
+/// Ids of the achievements unlocked on this device.
+#[derive(Resource, Debug, Default)]
+pub struct UnlockedAchievements(pub Vec<String>);
+
+/// Triggered the first time an achievement is unlocked.
+#[derive(Event, Debug, Clone)]
+pub struct AchievementUnlocked {
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Resource, Debug, Default)]
+struct Counters {
+    /// Replays don't unlock anything.
+    replay: bool,
+    kills: u32,
+    boss_kills: u32,
+    /// Seconds since the player was last hurt.
+    no_damage: f32,
+}
+
+impl Counters {
+    fn is_met(&self, condition: Condition, survived: f32, level: u32) -> bool {
+        match condition {
+            Condition::Kills(kills) => self.kills >= kills,
+            Condition::TimeSurvived(secs) => survived >= secs,
+            Condition::LevelReached(reached) => level >= reached,
+            Condition::BossKills(kills) => self.boss_kills >= kills,
+            Condition::NoDamageMinutes(minutes) => self.no_damage >= minutes * 60.,
+        }
+    }
+}
+
+fn reset_counters(mut commands: Commands, replay: Option<Res<Replay>>) {
+    commands.insert_resource(Counters {
+        replay: replay.is_some(),
+        ..default()
+    });
+}
+
+fn count_kill(
+    on: On<EnemyDeathEvent>,
+    mut commands: Commands,
+    mut counters: ResMut<Counters>,
+    survival: Res<SurvivalTimer>,
+    xp: Single<&Xp, With<Player>>,
+    assets: Res<AchievementAssets>,
+    mut unlocked: ResMut<UnlockedAchievements>,
+) {
+    counters.kills += 1;
+    if on.boss {
+        counters.boss_kills += 1;
+    }
+    unlock(
+        &mut commands,
+        &counters,
+        &survival,
+        &xp,
+        &assets,
+        &mut unlocked,
+    );
+}
+
+fn reset_no_damage(_: On<PlayerHurt>, mut counters: ResMut<Counters>) {
+    counters.no_damage = 0.;
+}
+
+fn count_time(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut counters: ResMut<Counters>,
+    survival: Res<SurvivalTimer>,
+    xp: Single<&Xp, With<Player>>,
+    assets: Res<AchievementAssets>,
+    mut unlocked: ResMut<UnlockedAchievements>,
+) {
+    counters.no_damage += time.delta_secs();
+    unlock(
+        &mut commands,
+        &counters,
+        &survival,
+        &xp,
+        &assets,
+        &mut unlocked,
+    );
+}
+
+/// Unlocks and saves every achievement newly met, levels are read from the player's `Xp`.
+fn unlock(
+    commands: &mut Commands,
+    counters: &Counters,
+    survival: &SurvivalTimer,
+    xp: &Xp,
+    assets: &AchievementAssets,
+    unlocked: &mut UnlockedAchievements,
+) {
+    if counters.replay {
+        return;
+    }
+    let survived = survival.0.elapsed_secs();
+    let mut changed = false;
+    for achievement in &assets.achievements {
+        if unlocked.0.contains(&achievement.id)
+            || !counters.is_met(achievement.condition, survived, xp.level)
+        {
+            continue;
+        }
+        unlocked.0.push(achievement.id.clone());
+        changed = true;
+        commands.trigger(AchievementUnlocked {
+            name: achievement.name.clone(),
+            description: achievement.description.clone(),
+        });
+    }
+    if changed {
+        storage::save(ACHIEVEMENTS_FILE, &unlocked.0);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::gameplay::level::RandomSeed;
 use crate::gameplay::player::Player;
 use crate::{AppSystems, PausableSystems, audio::sound_fx, gameplay::movement::MovementController};
 use bevy::platform::collections::HashMap;
@@ -66,6 +67,7 @@ fn trigger_step_sound_effect(
     mut commands: Commands,
     mut step_query: Query<&CharacterAnimation, With<Player>>,
     audio_settings: Res<AudioSettings>,
+    mut rng: ResMut<RandomSeed>,
 ) {
     for animation in &mut step_query {
         let Some(steps) = animation.current.frames.steps.as_ref() else {
@@ -73,8 +75,7 @@ fn trigger_step_sound_effect(
         };
 
         if animation.changed() && steps.frames.contains(&animation.current.frame) {
-            let rng = &mut rand::rng();
-            let random_step = steps.samples.choose(rng).unwrap().clone();
+            let random_step = steps.samples.choose(&mut rng.effects).unwrap().clone();
             commands.spawn(sound_fx(random_step.handle, &audio_settings));
         }
     }
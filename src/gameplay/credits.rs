@@ -0,0 +1,109 @@
+use crate::gameplay::enemy::EnemyDeathEvent;
+use crate::gameplay::level::{CreditSettings, LevelAssets};
+use crate::gameplay::run::RunSetup;
+use crate::hud::Announcement;
+use crate::hud::timer::SurvivalTimer;
+use crate::menu::Menu;
+use crate::profile::Profile;
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.add_observer(earn_credits);
+    app.add_systems(OnEnter(Menu::GameOver), settle_death.in_set(Settlement));
+    app.add_systems(
+        OnEnter(Menu::ExtractionSuccess),
+        settle_extraction.in_set(Settlement),
+    );
+}
+
+/// Computes `Credits::settled` when the run ends, screens showing it run after.
+#[derive(SystemSet, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Settlement;
+
+/// Credits earned during the current run.
+#[derive(Resource, Debug, Default)]
+pub struct Credits {
+    pub earned: f32,
+    /// Price of the clone, inserted by `spawn_level`.
+    pub clone_cost: u32,
+    /// Part of the clone price the profile could not pay, taken from the settled credits.
+    pub unpaid: u32,
+    /// What the run is worth once ended, after the death or extraction multiplier.
+    pub settled: Option<u32>,
+}
+
+impl Credits {
+    pub fn new(clone_cost: u32, unpaid: u32) -> Self {
+        Self {
+            clone_cost,
+            unpaid,
+            ..default()
+        }
+    }
+
+    /// Part of the clone price paid from the profile at spawn.
+    pub fn paid(&self) -> u32 {
+        self.clone_cost - self.unpaid
+    }
+
+    fn settle(&mut self, settled: f32) {
+        self.settled = Some((settled as u32).saturating_sub(self.unpaid));
+    }
+
+    /// Share of the clone cost earned back, 1 once paid off.
+    pub fn break_even(&self) -> f32 {
+        if self.clone_cost == 0 {
+            return 1.;
+        }
+        (self.earned / self.clone_cost as f32).min(1.)
+    }
+
+    pub fn paid_off(&self) -> bool {
+        self.earned >= self.clone_cost as f32
+    }
+}
+
+/// Price of the next clone, growing with every meta upgrade tier bought.
+pub fn clone_cost(settings: &CreditSettings, profile: &Profile, reduction: f32) -> u32 {
+    let tiers: u32 = profile.upgrades.values().sum();
+    let cost = settings.clone_cost + settings.clone_cost_per_tier * tiers as f32;
+    (cost * (1. - reduction).max(0.)) as u32
+}
+
+fn earn_credits(
+    on: On<EnemyDeathEvent>,
+    mut commands: Commands,
+    mut credits: ResMut<Credits>,
+    survival: Res<SurvivalTimer>,
+    level_assets: Res<LevelAssets>,
+) {
+    let was_paid_off = credits.paid_off();
+    let minutes = survival.0.elapsed_secs() / 60.;
+    let difficulty = 1. + minutes * level_assets.credits.difficulty_per_minute;
+    credits.earned += on.bounty * difficulty;
+    if !was_paid_off && credits.paid_off() {
+        commands.trigger(Announcement("Clone paid for itself".into()));
+    }
+}
+
+/// A clone dying before paying for itself brings nothing back.
+fn settle_death(mut credits: ResMut<Credits>, level_assets: Res<LevelAssets>) {
+    let settled = if credits.paid_off() {
+        credits.earned * level_assets.credits.death_multiplier
+    } else {
+        0.
+    };
+    credits.settle(settled);
+}
+
+fn settle_extraction(
+    mut credits: ResMut<Credits>,
+    level_assets: Res<LevelAssets>,
+    run_setup: Res<RunSetup>,
+) {
+    let reward = level_assets
+        .extraction(run_setup.extraction)
+        .map_or(1., |duration| duration.reward);
+    let settled = credits.earned * reward;
+    credits.settle(settled);
+}
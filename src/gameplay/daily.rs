@@ -0,0 +1,145 @@
+//! One run a day on a date-derived seed, only the first attempt is scored.
+
+use crate::asset_tracking::LoadResource;
+use crate::gameplay::modifier::ModifierSet;
+use crate::gameplay::replay::Replay;
+use crate::gameplay::run::{RunSetup, RunSummary, Summary, seed_from_text};
+use crate::menu::Menu;
+use crate::profile::Profile;
+use crate::profile::storage;
+use crate::screen::Screen;
+use bevy::prelude::*;
+use ron_asset_manager::prelude::RonAsset;
+use serde::{Deserialize, Serialize};
+
+const RESULTS_FILE: &str = "daily.ron";
+
+pub fn plugin(app: &mut App) {
+    app.load_resource::<DailyAssets>("daily.ron");
+    app.add_systems(
+        OnEnter(Screen::Gameplay(false)),
+        start_daily.run_if(not(resource_exists::<Replay>)),
+    );
+    app.add_systems(
+        OnEnter(Menu::GameOver),
+        record_daily
+            .after(Summary)
+            .run_if(resource_exists::<ScoredAttempt>),
+    );
+    app.add_systems(
+        OnEnter(Menu::ExtractionSuccess),
+        record_daily
+            .after(Summary)
+            .run_if(resource_exists::<ScoredAttempt>),
+    );
+    app.add_systems(OnExit(Screen::Gameplay(false)), end_daily);
+}
+
+#[derive(Resource, Asset, RonAsset, TypePath, Deserialize, Debug, Clone)]
+pub struct DailyAssets {
+    /// Rules of each day, one after the other.
+    pub rotation: Vec<ModifierSet>,
+}
+
+/// The challenge of a given day.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Daily {
+    /// `YYYY-MM-DD`, in UTC.
+    pub date: String,
+    pub seed: u32,
+    pub rules: ModifierSet,
+}
+
+impl DailyAssets {
+    /// `None` when the date is unknown or daily.ron has no rules.
+    pub fn today(&self) -> Option<Daily> {
+        let day = days_since_epoch()?;
+        let date = date(day);
+        let rules = (day as usize).checked_rem(self.rotation.len())?;
+        Some(Daily {
+            seed: seed_from_text(&format!("daily-{date}"))?,
+            date,
+            rules: self.rotation[rules].clone(),
+        })
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn days_since_epoch() -> Option<u64> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()?;
+    Some(now.as_secs() / 86_400)
+}
+
+#[cfg(target_family = "wasm")]
+fn days_since_epoch() -> Option<u64> {
+    None
+}
+
+/// Gregorian date of a day counted from 1970-01-01.
+fn date(day: u64) -> String {
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = day as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + i64::from(m <= 2);
+    format!("{y:04}-{m:02}-{d:02}")
+}
+
+/// Scored attempt of a daily.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DailyResult {
+    pub date: String,
+    pub summary: RunSummary,
+}
+
+pub fn results() -> Vec<DailyResult> {
+    storage::load_or_back_up(RESULTS_FILE).unwrap_or_default()
+}
+
+impl Daily {
+    /// Started before, even if it was quit before its end.
+    pub fn attempted(&self, profile: &Profile, results: &[DailyResult]) -> bool {
+        profile.daily_attempts.contains(&self.date)
+            || results.iter().any(|result| result.date == self.date)
+    }
+}
+
+/// Present during the first attempt of a daily, the only one scored.
+#[derive(Resource)]
+struct ScoredAttempt;
+
+/// Marks the daily as attempted when it starts, so quitting it doesn't give another try.
+fn start_daily(mut commands: Commands, run_setup: Res<RunSetup>, mut profile: ResMut<Profile>) {
+    let Some(daily) = &run_setup.daily else {
+        return;
+    };
+    if daily.attempted(&profile, &results()) {
+        return;
+    }
+    profile.daily_attempts.push(daily.date.clone());
+    profile.save();
+    commands.insert_resource(ScoredAttempt);
+}
+
+fn record_daily(run_setup: Res<RunSetup>, summary: Res<RunSummary>) {
+    let Some(daily) = &run_setup.daily else {
+        return;
+    };
+    let mut results = results();
+    results.push(DailyResult {
+        date: daily.date.clone(),
+        summary: summary.clone(),
+    });
+    storage::save(RESULTS_FILE, &results);
+}
+
+fn end_daily(mut commands: Commands) {
+    commands.remove_resource::<ScoredAttempt>();
+}
@@ -18,6 +18,14 @@ pub struct Damage {
     pub cooldown: f32,
 }
 
+/// Enemy flagged as a boss in enemy.ron.
+#[derive(Component, Reflect, Debug)]
+pub struct Boss;
+
+/// Credits earned by killing the enemy.
+#[derive(Component, Reflect, Debug)]
+pub struct Bounty(pub f32);
+
 #[derive(Component, Reflect, Debug)]
 pub struct DamageCooldown {
     pub timer: Timer,
@@ -25,6 +33,8 @@ pub struct DamageCooldown {
 
 #[derive(Resource, Asset, RonAsset, TypePath, Deserialize, Debug, Clone)]
 pub struct EnemyAssets {
+    /// Distance from the player at which enemies appear, just outside a 1080p view.
+    pub spawn_distance: f32,
     #[asset]
     pub types: Vec<EnemyType>,
     #[asset]
@@ -38,6 +48,16 @@ pub struct EnemyType {
     pub max_health: f32,
     pub damage: f32,
     pub cooldown: f32,
+    pub credits: f32,
+    /// Spawns in one wave out of this many, in every wave when missing.
+    #[serde(default)]
+    pub wave_interval: Option<u32>,
+    /// Bosses are shown on the minimap.
+    #[serde(default)]
+    pub boss: bool,
+    /// Strong enemies get an arrow pointing at them while off-screen.
+    #[serde(default)]
+    pub indicator: bool,
     #[asset]
     pub sprite: Shandle<Animation>,
 }
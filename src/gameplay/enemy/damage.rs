@@ -1,7 +1,8 @@
 use crate::audio::{AudioSettings, sound_fx};
 use crate::gameplay::animation::CharacterAnimation;
-use crate::gameplay::enemy::asset::{Enemy, EnemyAssets};
+use crate::gameplay::enemy::asset::{Boss, Bounty, Enemy, EnemyAssets};
 use crate::gameplay::health::Health;
+use crate::gameplay::level::RandomSeed;
 use crate::gameplay::movement::MovementController;
 use crate::hud::elimination::EliminationCount;
 use crate::screen::Screen;
@@ -12,14 +13,20 @@ use rand::prelude::*;
 
 pub fn plugin(app: &mut App) {
     app.add_systems(
-        Update,
+        FixedUpdate,
         (
             update_damage_timer.in_set(AppSystems::TickTimers),
-            (check_damage, flash_when_hurt).in_set(AppSystems::Update),
+            check_damage.in_set(AppSystems::Update),
         )
             .in_set(PausableSystems)
             .run_if(in_state(Screen::Gameplay(false))),
     );
+    app.add_systems(
+        Update,
+        flash_when_hurt
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems),
+    );
 }
 
 #[derive(Component, Reflect)]
@@ -31,6 +38,9 @@ pub struct Hurt {
 #[derive(Event)]
 pub struct EnemyDeathEvent {
     pub pos: Vec2,
+    /// Credits earned for the kill, before the difficulty multiplier.
+    pub bounty: f32,
+    pub boss: bool,
 }
 
 fn check_damage(
@@ -38,12 +48,20 @@ fn check_damage(
     enemy_assets: Res<EnemyAssets>,
     audio_settings: Res<AudioSettings>,
     mut elimination_count: ResMut<EliminationCount>,
+    mut rng: ResMut<RandomSeed>,
     enemies: Query<
-        (Entity, &Health, &mut LinearVelocity, &GlobalTransform),
+        (
+            Entity,
+            &Health,
+            &mut LinearVelocity,
+            &GlobalTransform,
+            Option<&Bounty>,
+            Has<Boss>,
+        ),
         (With<Enemy>, Changed<Health>),
     >,
 ) {
-    for (entity, health, mut vel, position) in enemies {
+    for (entity, health, mut vel, position, bounty, boss) in enemies {
         if health.current == health.max {
             continue;
         }
@@ -51,6 +69,8 @@ fn check_damage(
         if dead {
             commands.trigger(EnemyDeathEvent {
                 pos: position.translation().truncate(),
+                bounty: bounty.map_or(0., |bounty| bounty.0),
+                boss,
             });
         }
         let mut entity = commands.entity(entity);
@@ -66,7 +86,7 @@ fn check_damage(
             timer: Timer::from_seconds(0.15, TimerMode::Once),
             dead,
         });
-        let sound = enemy_assets.hit_sounds.choose(&mut rand::rng()).unwrap();
+        let sound = enemy_assets.hit_sounds.choose(&mut rng.effects).unwrap();
         commands.spawn(sound_fx(sound.handle.clone(), &audio_settings));
     }
 }
@@ -1,4 +1,3 @@
-use crate::gameplay::enemy::spawner::EnemyRoot;
 use bevy::prelude::*;
 
 pub mod asset;
@@ -8,6 +7,7 @@ mod spawner;
 
 pub use damage::EnemyDeathEvent;
 pub use damage::Hurt;
+pub use spawner::{EnemyRoot, SpawnRate, enemy};
 
 pub fn plugin(app: &mut App) {
     app.add_plugins((
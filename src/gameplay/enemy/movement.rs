@@ -6,7 +6,7 @@ use bevy::prelude::*;
 
 pub fn plugin(app: &mut App) {
     app.add_systems(
-        Update,
+        FixedUpdate,
         (
             update_move_timer.in_set(AppSystems::TickTimers),
             move_enemies.in_set(AppSystems::Update),
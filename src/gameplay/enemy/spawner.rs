@@ -1,9 +1,13 @@
 use crate::gameplay::animation::Animation;
-use crate::gameplay::enemy::asset::{Damage, Enemy, EnemyAssets, EnemyType};
+use crate::gameplay::enemy::asset::{Boss, Bounty, Damage, Enemy, EnemyAssets, EnemyType};
 use crate::gameplay::health::Health;
 use crate::gameplay::layer::GameLayer;
 use crate::gameplay::level::{RandomSeed, WorldEntity};
+use crate::gameplay::modifier::RunModifiers;
+use crate::gameplay::player::Player;
 use crate::gameplay::{animation::CharacterAnimation, movement::MovementController};
+use crate::hud::indicator::OffscreenIndicator;
+use crate::hud::minimap::MinimapMarker;
 use crate::screen::Screen;
 use crate::{AppSystems, PausableSystems};
 use avian2d::prelude::{Collider, CollisionLayers, DebugRender, LockedAxes, Mass, RigidBody};
@@ -12,10 +16,11 @@ use bevy::prelude::*;
 use bevy::sprite::Anchor;
 use rand::RngExt;
 use std::f32::consts::PI;
+use std::time::Duration;
 
 pub fn plugin(app: &mut App) {
     app.add_systems(
-        Update,
+        FixedUpdate,
         (
             update_spawn_timer.in_set(AppSystems::TickTimers),
             spawn_enemies.in_set(AppSystems::Update),
@@ -24,6 +29,20 @@ pub fn plugin(app: &mut App) {
             .run_if(in_state(Screen::Gameplay(false))),
     );
     app.insert_resource(SpawnTimer::default());
+    app.add_systems(OnEnter(Screen::Gameplay(false)), reset_spawns);
+}
+
+/// Multiplier of the enemy waves frequency.
+#[derive(Resource, Debug)]
+pub struct SpawnRate(pub f32);
+
+/// Waves spawned since the start of the run.
+#[derive(Resource, Debug, Default)]
+struct WaveCount(u32);
+
+fn reset_spawns(mut commands: Commands) {
+    commands.insert_resource(SpawnRate(1.));
+    commands.insert_resource(WaveCount::default());
 }
 
 #[derive(Resource)]
@@ -35,52 +54,64 @@ impl Default for SpawnTimer {
     }
 }
 
-fn update_spawn_timer(time: Res<Time>, mut timer: ResMut<SpawnTimer>) {
+fn update_spawn_timer(
+    time: Res<Time>,
+    rate: Res<SpawnRate>,
+    modifiers: Res<RunModifiers>,
+    mut timer: ResMut<SpawnTimer>,
+) {
+    let rate = rate.0 * modifiers.spawn_rate;
+    timer
+        .0
+        .set_duration(Duration::from_secs_f32(1. / rate.max(0.1)));
     timer.0.tick(time.delta());
 }
 
 fn spawn_enemies(
     mut commands: Commands,
     timer: Res<SpawnTimer>,
+    mut waves: ResMut<WaveCount>,
     mut rng: ResMut<RandomSeed>,
     enemy_assets: Res<EnemyAssets>,
     mut animations: ResMut<Assets<Animation>>,
     mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
     root: Single<Entity, With<EnemyRoot>>,
-    camera: Single<(&Camera, &GlobalTransform)>,
-    window: Single<&Window>,
+    player: Single<&Transform, With<Player>>,
 ) {
     if !timer.0.just_finished() {
         return;
     }
+    waves.0 += 1;
 
     let Some(mut root) = commands.get_entity(*root).ok() else {
         return;
     };
 
-    let resolution = Vec2::new(window.resolution.width(), window.resolution.height());
-    let (camera, camera_transform) = *camera;
-    let un = camera
-        .viewport_to_world_2d(camera_transform, Vec2::ZERO)
-        .unwrap();
-    let deux = camera
-        .viewport_to_world_2d(camera_transform, resolution)
-        .unwrap();
-
-    let half_size = (deux - un).abs() / 2.0;
-    let center = (un + deux) / 2.0;
-    let radius = half_size.length() + 50.0;
+    // Spawning around the player rather than the camera keeps the window out of the simulation.
+    let center = player.translation.truncate();
+    let radius = enemy_assets.spawn_distance;
 
     root.with_children(|parent| {
-        for enemy_type in enemy_assets.types.iter() {
-            let angle = rng.0.random_range(0.0..2.0 * PI);
+        let in_wave = |enemy_type: &&EnemyType| {
+            enemy_type
+                .wave_interval
+                .is_none_or(|interval| waves.0.is_multiple_of(interval.max(1)))
+        };
+        for enemy_type in enemy_assets.types.iter().filter(in_wave) {
+            let angle = rng.spawns.random_range(0.0..2.0 * PI);
             let position = center + Vec2::new(angle.cos(), angle.sin()) * radius;
-            parent.spawn(enemy(
+            let mut enemy = parent.spawn(enemy(
                 position,
                 enemy_type,
                 &mut animations,
                 &mut texture_atlas_layouts,
             ));
+            if enemy_type.boss {
+                enemy.insert((Boss, MinimapMarker::Boss));
+            }
+            if enemy_type.indicator {
+                enemy.insert(OffscreenIndicator::Threat);
+            }
         }
     });
 }
@@ -107,6 +138,7 @@ pub fn enemy(
                 cooldown: enemy.cooldown,
             },
             Health::new(enemy.max_health),
+            Bounty(enemy.credits),
             GameLayer::Enemy,
         ),
         sprite,
@@ -0,0 +1,175 @@
+use crate::gameplay::enemy::SpawnRate;
+use crate::gameplay::layer::GameLayer;
+use crate::gameplay::level::{Capsule, LevelAssets, PlanetAnchor, RandomSeed};
+use crate::gameplay::player::Player;
+use crate::gameplay::run::ExtractionCountdown;
+use crate::gameplay::tilemap::asset::TilesetAssets;
+use crate::gameplay::tilemap::{planet_pixel_size, planet_position, world_position};
+use crate::hud::indicator::OffscreenIndicator;
+use crate::hud::minimap::MinimapMarker;
+use crate::menu::Menu;
+use crate::screen::Screen;
+use crate::{AppSystems, PausableSystems};
+use avian2d::prelude::{Collider, CollidingEntities, CollisionLayers, RigidBody, Sensor};
+use bevy::prelude::*;
+use rand::RngExt;
+use std::f32::consts::FRAC_PI_2;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay(false)), reset_extraction);
+    app.add_systems(
+        FixedUpdate,
+        (
+            (update_extraction_ready, tick_landing).in_set(AppSystems::TickTimers),
+            call_extraction.in_set(AppSystems::Update),
+        )
+            .in_set(PausableSystems),
+    );
+    app.add_systems(
+        Update,
+        record_extraction_input
+            .in_set(AppSystems::RecordInput)
+            .in_set(PausableSystems),
+    );
+}
+
+const EXTRACTION_KEY: KeyCode = KeyCode::KeyE;
+const EXTRACTION_BUTTON: GamepadButton = GamepadButton::North;
+const ZONE_COLOR: Color = Color::srgba(0.2, 1.0, 0.4, 0.25);
+
+#[derive(Resource, Debug, Default)]
+pub enum Extraction {
+    /// The extraction time chosen before the run has not elapsed yet.
+    #[default]
+    Waiting,
+    Ready,
+    /// The ship is landing: the player must stay in the zone until the countdown ends.
+    Landing {
+        countdown: Timer,
+    },
+}
+
+/// Set by the input, consumed by the next fixed tick.
+#[derive(Resource, Debug, Default)]
+pub struct ExtractionRequest(pub bool);
+
+#[derive(Component, Reflect)]
+pub struct LandingZone;
+
+fn reset_extraction(mut commands: Commands) {
+    commands.insert_resource(Extraction::default());
+    commands.insert_resource(ExtractionRequest::default());
+}
+
+fn update_extraction_ready(
+    countdown: Res<ExtractionCountdown>,
+    mut extraction: ResMut<Extraction>,
+) {
+    if matches!(*extraction, Extraction::Waiting) && countdown.0.is_finished() {
+        *extraction = Extraction::Ready;
+    }
+}
+
+fn record_extraction_input(
+    input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut request: ResMut<ExtractionRequest>,
+) {
+    let gamepad_pressed = gamepads
+        .iter()
+        .next()
+        .is_some_and(|gamepad| gamepad.just_pressed(EXTRACTION_BUTTON));
+    if input.just_pressed(EXTRACTION_KEY) || gamepad_pressed {
+        request.0 = true;
+    }
+}
+
+fn call_extraction(
+    mut commands: Commands,
+    mut request: ResMut<ExtractionRequest>,
+    mut extraction: ResMut<Extraction>,
+    mut rng: ResMut<RandomSeed>,
+    level_assets: Res<LevelAssets>,
+    tileset_assets: Res<TilesetAssets>,
+    player: Single<&Transform, With<Player>>,
+    capsule: Single<&Transform, (With<Capsule>, Without<Player>)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    if !std::mem::take(&mut request.0) || !matches!(*extraction, Extraction::Ready) {
+        return;
+    }
+
+    let settings = &level_assets.extraction;
+    let planet_size = planet_pixel_size(&level_assets, &tileset_assets);
+    let player = player.translation.truncate();
+    // Lands on the far side of the player, seen from the crash site.
+    let away = (player - capsule.translation.truncate())
+        .try_normalize()
+        .map_or(0.0, |direction| direction.to_angle());
+    let angle = away + rng.extraction.random_range(-FRAC_PI_2..FRAC_PI_2);
+    let distance = rng
+        .extraction
+        .random_range(settings.min_distance..settings.max_distance);
+    let planet = planet_position(player + Vec2::from_angle(angle) * distance, planet_size);
+
+    commands.spawn(landing_zone(
+        planet,
+        world_position(planet, player, planet_size),
+        settings.radius,
+        &mut meshes,
+        &mut materials,
+    ));
+    *extraction = Extraction::Landing {
+        countdown: Timer::from_seconds(settings.countdown, TimerMode::Once),
+    };
+}
+
+fn landing_zone(
+    planet: Vec2,
+    position: Vec2,
+    radius: f32,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+) -> impl Bundle {
+    (
+        Name::new("Landing Zone"),
+        LandingZone,
+        PlanetAnchor(planet),
+        MinimapMarker::Extraction,
+        OffscreenIndicator::Objective,
+        GameLayer::Zone,
+        DespawnOnExit(Screen::Gameplay(false)),
+        Mesh2d(meshes.add(Circle::new(radius))),
+        MeshMaterial2d(materials.add(ZONE_COLOR)),
+        Transform::from_translation(position.extend(0.)),
+        (
+            RigidBody::Static,
+            Collider::circle(radius),
+            Sensor,
+            CollisionLayers::new(GameLayer::Zone, [GameLayer::Player]),
+            CollidingEntities::default(),
+        ),
+    )
+}
+
+fn tick_landing(
+    time: Res<Time>,
+    mut extraction: ResMut<Extraction>,
+    zone: Single<&CollidingEntities, With<LandingZone>>,
+    player: Single<Entity, With<Player>>,
+    level_assets: Res<LevelAssets>,
+    mut spawn_rate: ResMut<SpawnRate>,
+    mut next: ResMut<NextState<Menu>>,
+) {
+    let Extraction::Landing { countdown } = &mut *extraction else {
+        return;
+    };
+    if zone.contains(&*player) {
+        countdown.tick(time.delta());
+    }
+    spawn_rate.0 = 1. + (level_assets.extraction.max_spawn_rate - 1.) * countdown.fraction();
+    if countdown.just_finished() {
+        next.set(Menu::ExtractionSuccess);
+    }
+}
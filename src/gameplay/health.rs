@@ -2,12 +2,19 @@ use bevy::color::palettes::tailwind::RED_500;
 use bevy::prelude::*;
 
 use crate::hud::{ProgressBar, progress_bar};
+use crate::{AppSystems, PausableSystems};
 
 const BAR_LENGTH: f32 = 20.0;
 const BAR_THICKNESS: f32 = 2.0;
 
 pub fn plugin(app: &mut App) {
     app.add_systems(Update, update_health_bar);
+    app.add_systems(
+        FixedUpdate,
+        regenerate
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems),
+    );
 }
 
 #[allow(dead_code)]
@@ -46,7 +53,31 @@ impl Health {
 
     pub fn inc_max_health(&mut self, amount: f32) {
         self.max = self.max + (self.max * amount);
-        self.current += self.max * amount;
+        self.heal(self.max * amount);
+    }
+
+    pub fn damage(&mut self, amount: f32) {
+        self.current = (self.current - amount).clamp(0., self.max);
+    }
+
+    /// Returns the amount of health actually restored.
+    pub fn heal(&mut self, amount: f32) -> f32 {
+        let before = self.current;
+        self.current = (self.current + amount).clamp(0., self.max);
+        self.current - before
+    }
+}
+
+/// Health points restored per second.
+#[derive(Component, Reflect, Debug, Default)]
+pub struct Regeneration(pub f32);
+
+fn regenerate(time: Res<Time>, mut query: Query<(&mut Health, &Regeneration)>) {
+    for (mut health, regeneration) in &mut query {
+        if health.is_dead() || health.current >= health.max {
+            continue;
+        }
+        health.heal(regeneration.0 * time.delta_secs());
     }
 }
 
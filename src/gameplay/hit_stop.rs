@@ -0,0 +1,57 @@
+use crate::AppSystems;
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<HitStop>();
+    app.add_systems(Update, update_hit_stop.in_set(AppSystems::TickTimers));
+}
+
+#[derive(Resource)]
+pub struct HitStop {
+    timer: Option<Timer>,
+    /// Off in the headless harness, where each update must run exactly one fixed tick.
+    pub enabled: bool,
+}
+
+impl Default for HitStop {
+    fn default() -> Self {
+        Self {
+            timer: None,
+            enabled: true,
+        }
+    }
+}
+
+impl HitStop {
+    pub fn trigger(&mut self, duration: f32) {
+        if !self.enabled {
+            return;
+        }
+        let remaining = self
+            .timer
+            .as_ref()
+            .map(|t| t.remaining_secs())
+            .unwrap_or_default();
+        if duration > remaining {
+            self.timer = Some(Timer::from_seconds(duration, TimerMode::Once));
+        }
+    }
+}
+
+fn update_hit_stop(
+    real_time: Res<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut hit_stop: ResMut<HitStop>,
+) {
+    let Some(timer) = hit_stop.timer.as_mut() else {
+        return;
+    };
+    if !virtual_time.is_paused() {
+        virtual_time.pause();
+    }
+    timer.tick(real_time.delta());
+    if timer.is_finished() {
+        hit_stop.timer = None;
+        virtual_time.unpause();
+    }
+}
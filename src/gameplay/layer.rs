@@ -10,6 +10,7 @@ pub fn plugin(app: &mut App) {
 pub enum GameLayer {
     #[default]
     Ground,
+    Zone,
     Loot,
     Enemy,
     AimZone,
@@ -21,6 +22,7 @@ impl GameLayer {
     fn z(&self) -> f32 {
         match self {
             GameLayer::Ground => -1.,
+            GameLayer::Zone => 1.,
             GameLayer::Loot => 5.,
             GameLayer::Enemy => 10.,
             GameLayer::AimZone => 15.,
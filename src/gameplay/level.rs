@@ -1,18 +1,25 @@
 use crate::asset_tracking::LoadResource;
 use crate::audio::AudioSettings;
+use crate::gameplay::GameLayer;
 use crate::gameplay::animation::Animation;
+use crate::gameplay::credits::{Credits, clone_cost};
 use crate::gameplay::enemy::enemy_root;
 use crate::gameplay::loot::loot_root;
+use crate::gameplay::modifier::{ModifierAssets, RunModifiers};
 use crate::gameplay::player::asset::PlayerAssets;
 use crate::gameplay::player::spawn_player;
 use crate::gameplay::player::weapon::{WeaponAssets, bullet_root};
+use crate::gameplay::run::RunSetup;
 use crate::gameplay::tilemap::asset::TilesetAssets;
-use crate::gameplay::tilemap::spawn_tilemap;
+use crate::gameplay::tilemap::{planet_pixel_size, planet_position, spawn_tilemap};
+use crate::hud::indicator::OffscreenIndicator;
+use crate::hud::minimap::MinimapMarker;
+use crate::profile::Profile;
+use crate::profile::shop::ShopAssets;
 use crate::{audio::music, screen::Screen};
 use bevy::prelude::*;
+use rand::SeedableRng;
 use rand::prelude::StdRng;
-use rand::rngs::ThreadRng;
-use rand::{Rng, SeedableRng};
 use ron_asset_manager::Shandle;
 use ron_asset_manager::prelude::RonAsset;
 use serde::Deserialize;
@@ -23,8 +30,42 @@ pub struct TilemapOrigin;
 #[derive(Component, Reflect)]
 pub struct WorldEntity;
 
+/// Fixed position on the planet, in pixels. The entity is moved to the closest wrapped
+/// position around the player.
+#[derive(Component, Reflect)]
+pub struct PlanetAnchor(pub Vec2);
+
+/// The crashed capsule, where the player starts.
+#[derive(Component, Reflect)]
+pub struct Capsule;
+
+/// Random streams of the run, all derived from its seed. Each subsystem draws from its own
+/// stream, so an extra draw in one of them doesn't change what happens in the others.
 #[derive(Resource)]
-pub struct RandomSeed(pub(crate) StdRng);
+pub struct RandomSeed {
+    pub spawns: StdRng,
+    pub loot: StdRng,
+    pub events: StdRng,
+    pub extraction: StdRng,
+    /// Chests placed on the planet when the level starts.
+    pub chests: StdRng,
+    /// Sounds and visual effects, which don't change the outcome of the run.
+    pub effects: StdRng,
+}
+
+impl RandomSeed {
+    pub fn new(seed: u32) -> Self {
+        let stream = |index: u64| StdRng::seed_from_u64((index << 32) | seed as u64);
+        Self {
+            spawns: stream(0),
+            loot: stream(1),
+            events: stream(2),
+            extraction: stream(3),
+            effects: stream(4),
+            chests: stream(5),
+        }
+    }
+}
 
 pub fn plugin(app: &mut App) {
     app.load_resource::<LevelAssets>("level.ron");
@@ -37,20 +78,64 @@ pub struct LevelAssets {
     pub music: Shandle<AudioSource>,
     #[asset]
     pub game_over: Shandle<AudioSource>,
-    #[serde(default = "random_seed")]
-    pub seed: u32,
+    #[asset]
+    pub capsule: Shandle<Image>,
+    /// Forces the seed of every run, random when missing.
+    #[serde(default)]
+    pub seed: Option<u32>,
     pub planet_width: u32,
     pub planet_height: u32,
+    /// Extraction times offered before a run, shortest first.
+    pub extraction_durations: Vec<ExtractionDuration>,
+    pub extraction: ExtractionSettings,
+    pub credits: CreditSettings,
 }
 
-#[derive(Component, Reflect)]
-pub struct GameplayMusic;
+impl LevelAssets {
+    /// Falls back to the first duration when the index is out of range, `None` when level.ron
+    /// lists no durations.
+    pub fn extraction(&self, index: usize) -> Option<&ExtractionDuration> {
+        self.extraction_durations
+            .get(index)
+            .or(self.extraction_durations.first())
+    }
+}
+
+#[derive(Deserialize, Debug, Copy, Clone)]
+pub struct ExtractionDuration {
+    pub minutes: u32,
+    /// Multiplier applied to the run rewards when the extraction succeeds.
+    pub reward: f32,
+}
 
-fn random_seed() -> u32 {
-    ThreadRng::default().next_u32()
+#[derive(Deserialize, Debug, Copy, Clone)]
+pub struct CreditSettings {
+    /// Difficulty added each minute survived, starting from 1.
+    pub difficulty_per_minute: f32,
+    /// Share of the earned credits kept when the clone dies after paying for itself.
+    pub death_multiplier: f32,
+    /// Price of a clone without any meta upgrade.
+    pub clone_cost: f32,
+    /// Added to the clone price for each meta upgrade tier bought.
+    pub clone_cost_per_tier: f32,
 }
 
-fn spawn_level(
+#[derive(Deserialize, Debug, Copy, Clone)]
+pub struct ExtractionSettings {
+    /// Distance range, in pixels, between the player and the landing zone.
+    pub min_distance: f32,
+    pub max_distance: f32,
+    pub radius: f32,
+    /// Seconds the player must spend in the landing zone.
+    pub countdown: f32,
+    /// Spawn rate reached at the end of the countdown.
+    pub max_spawn_rate: f32,
+}
+
+#[derive(Component, Reflect)]
+pub struct GameplayMusic;
+
+pub fn spawn_level(
     mut commands: Commands,
     level_assets: Res<LevelAssets>,
     tileset_assets: Res<TilesetAssets>,
@@ -63,13 +148,42 @@ fn spawn_level(
     mut materials: ResMut<Assets<ColorMaterial>>,
     mut images: ResMut<Assets<Image>>,
     audio_settings: Res<AudioSettings>,
+    run_setup: Res<RunSetup>,
+    mut profile: ResMut<Profile>,
+    shop_assets: Res<ShopAssets>,
+    modifier_assets: Res<ModifierAssets>,
 ) {
-    info!("Loading level with seed: {}", level_assets.seed);
+    info!("Loading level with seed: {}", run_setup.seed);
     camera.translation = Vec3::ZERO;
 
-    let rng = StdRng::seed_from_u64(level_assets.seed as u64);
+    let modifiers = RunModifiers::new(
+        run_setup
+            .modifier_sets(&modifier_assets)
+            .into_iter()
+            .flat_map(|set| &set.modifiers),
+    );
+    // A replay runs with the bonuses it was recorded with, and costs nothing.
+    let (bonuses, credits) = match run_setup.bonuses {
+        Some(bonuses) => (bonuses, Credits::default()),
+        None => {
+            let bonuses = shop_assets.bonuses(&profile);
+            let cost = clone_cost(
+                &level_assets.credits,
+                &profile,
+                bonuses.clone_cost_reduction,
+            );
+            // A broke profile still gets its clone, what it can't pay is taken from the earnings.
+            let paid = cost.min(profile.credits);
+            profile.credits -= paid;
+            profile.save();
+            (bonuses, Credits::new(cost, cost - paid))
+        }
+    };
+    commands.insert_resource(credits);
+
+    let planet_size = planet_pixel_size(&level_assets, &tileset_assets);
 
-    commands.insert_resource(RandomSeed(rng));
+    commands.insert_resource(RandomSeed::new(run_setup.seed));
 
     commands
         .spawn((
@@ -88,16 +202,47 @@ fn spawn_level(
             spawn_player(
                 parent,
                 &player_assets,
+                player_assets.character(run_setup.character),
+                &bonuses,
+                &modifiers,
                 &weapon_assets,
                 &mut meshes,
                 &mut materials,
                 &mut animations,
                 &mut texture_atlas_layouts,
             );
+            parent.spawn(capsule(
+                &level_assets,
+                planet_position(Vec2::ZERO, planet_size),
+            ));
             parent.spawn(enemy_root());
             parent.spawn(loot_root());
             parent.spawn(bullet_root());
 
-            spawn_tilemap(parent, &level_assets, &tileset_assets, &mut images);
+            spawn_tilemap(
+                parent,
+                run_setup.seed,
+                &level_assets,
+                &tileset_assets,
+                &mut images,
+            );
         });
+    commands.insert_resource(modifiers);
+}
+
+fn capsule(level_assets: &LevelAssets, planet: Vec2) -> impl Bundle {
+    (
+        Name::new("Capsule"),
+        Capsule,
+        PlanetAnchor(planet),
+        MinimapMarker::Landmark,
+        OffscreenIndicator::Landmark,
+        GameLayer::Zone,
+        Sprite {
+            image: level_assets.capsule.handle.clone(),
+            custom_size: Some(Vec2::splat(96.)),
+            ..default()
+        },
+        Transform::default(),
+    )
 }
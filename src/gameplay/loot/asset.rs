@@ -12,4 +12,28 @@ pub fn plugin(app: &mut App) {
 pub struct LootAssets {
     #[asset]
     pub xp: Shandle<Image>,
+    #[asset]
+    pub heal: HealLoot,
+    #[asset]
+    pub chest: ChestLoot,
+}
+
+#[derive(RonAsset, Deserialize, Clone, Debug)]
+pub struct HealLoot {
+    #[asset]
+    pub sprite: Shandle<Image>,
+    /// Probability for a killed enemy to drop a first-aid capsule.
+    pub chance: f64,
+    pub amount: f32,
+}
+
+#[derive(RonAsset, Deserialize, Clone, Debug)]
+pub struct ChestLoot {
+    #[asset]
+    pub sprite: Shandle<Image>,
+    /// Probability for a killed enemy to drop a chest.
+    pub chance: f64,
+    /// Chests placed on the planet when the level starts.
+    pub world_count: u32,
+    pub max_upgrades: u32,
 }
@@ -0,0 +1,149 @@
+use crate::gameplay::GameLayer;
+use crate::gameplay::enemy::EnemyDeathEvent;
+use crate::gameplay::level::{LevelAssets, PlanetAnchor, RandomSeed, WorldEntity, spawn_level};
+use crate::gameplay::loot::LootRoot;
+use crate::gameplay::loot::asset::LootAssets;
+use crate::gameplay::player::Player;
+use crate::gameplay::player::weapon::{WeaponAssets, WeaponSlots};
+use crate::gameplay::run::RunSetup;
+use crate::gameplay::tilemap::asset::TilesetAssets;
+use crate::gameplay::tilemap::{planet_pixel_size, world_position};
+use crate::gameplay::upgrade::Upgrade;
+use crate::menu::Menu;
+use crate::profile::Profile;
+use crate::profile::shop::ShopAssets;
+use crate::screen::Screen;
+use crate::{AppSystems, PausableSystems};
+use avian2d::debug_render::DebugRender;
+use avian2d::prelude::{
+    Collider, CollidingEntities, CollisionLayers, LockedAxes, RigidBody, Sensor,
+};
+use bevy::app::App;
+use bevy::color::palettes::tailwind::YELLOW_500;
+use bevy::prelude::*;
+use rand::RngExt;
+use ron_asset_manager::Shandle;
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<ChestContents>();
+    app.world_mut().add_observer(drop_chest);
+    app.add_systems(
+        OnEnter(Screen::Gameplay(false)),
+        spawn_world_chests.after(spawn_level),
+    );
+    app.add_systems(
+        FixedUpdate,
+        open_chest
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems)
+            .run_if(in_state(Screen::Gameplay(false))),
+    );
+}
+
+#[derive(Component, Reflect, Default)]
+pub struct Chest;
+
+/// Upgrades of the opened chest, granted once collected.
+#[derive(Resource, Debug, Default)]
+pub struct ChestContents(pub Vec<Upgrade>);
+
+/// Rerolls of chest contents left for the current run.
+#[derive(Resource, Debug, Default)]
+pub struct RerollTokens(pub u32);
+
+pub fn roll_upgrades(rng: &mut RandomSeed, max_upgrades: u32, offered: &[Upgrade]) -> Vec<Upgrade> {
+    let count = rng.loot.random_range(1..=max_upgrades);
+    (0..count)
+        .map(|_| offered[rng.loot.random_range(0..offered.len())])
+        .collect()
+}
+
+fn spawn_world_chests(
+    mut commands: Commands,
+    loot_assets: Res<LootAssets>,
+    level_assets: Res<LevelAssets>,
+    tileset_assets: Res<TilesetAssets>,
+    profile: Res<Profile>,
+    shop_assets: Res<ShopAssets>,
+    run_setup: Res<RunSetup>,
+    mut rng: ResMut<RandomSeed>,
+) {
+    commands.insert_resource(RerollTokens(
+        run_setup.bonuses(&shop_assets, &profile).rerolls,
+    ));
+
+    let planet_size = planet_pixel_size(&level_assets, &tileset_assets);
+    for _ in 0..loot_assets.chest.world_count {
+        let planet = Vec2::new(
+            rng.chests.random_range(0.0..planet_size.x),
+            rng.chests.random_range(0.0..planet_size.y),
+        );
+        commands.spawn((
+            chest(
+                &loot_assets.chest.sprite,
+                world_position(planet, Vec2::ZERO, planet_size),
+            ),
+            PlanetAnchor(planet),
+            DespawnOnExit(Screen::Gameplay(false)),
+        ));
+    }
+}
+
+fn drop_chest(
+    on: On<EnemyDeathEvent>,
+    root: Single<Entity, With<LootRoot>>,
+    loot_assets: Res<LootAssets>,
+    mut rng: ResMut<RandomSeed>,
+    mut commands: Commands,
+) {
+    if !rng.loot.random_bool(loot_assets.chest.chance) {
+        return;
+    }
+    let Some(mut root) = commands.get_entity(*root).ok() else {
+        return;
+    };
+    // Moved with the enemies when the world is recentered.
+    root.with_child((chest(&loot_assets.chest.sprite, on.pos), WorldEntity));
+}
+
+fn chest(sprite: &Shandle<Image>, position: Vec2) -> impl Bundle {
+    (
+        Name::new("Chest"),
+        Chest,
+        GameLayer::Loot,
+        Sprite::from_image(sprite.handle.clone()),
+        Transform::from_translation(position.extend(1.0)).with_scale(Vec2::splat(3.0).extend(1.0)),
+        (
+            RigidBody::Static,
+            Collider::circle(8.),
+            Sensor,
+            LockedAxes::ROTATION_LOCKED,
+            CollisionLayers::new(GameLayer::Loot, [GameLayer::Player]),
+            CollidingEntities::default(),
+        ),
+        DebugRender::default().with_collider_color(YELLOW_500.into()),
+    )
+}
+
+fn open_chest(
+    mut commands: Commands,
+    player: Single<&CollidingEntities, With<Player>>,
+    chests: Query<(), With<Chest>>,
+    slots: Single<&WeaponSlots>,
+    loot_assets: Res<LootAssets>,
+    weapon_assets: Res<WeaponAssets>,
+    mut rng: ResMut<RandomSeed>,
+    mut next: ResMut<NextState<Menu>>,
+) {
+    let Some(chest) = player.iter().find(|e| chests.contains(**e)) else {
+        return;
+    };
+    commands.entity(*chest).despawn();
+    let upgrades = roll_upgrades(
+        &mut rng,
+        loot_assets.chest.max_upgrades,
+        &Upgrade::offered(&slots, &weapon_assets),
+    );
+    commands.insert_resource(ChestContents(upgrades));
+    next.set(Menu::Chest);
+}
@@ -0,0 +1,62 @@
+use crate::gameplay::GameLayer;
+use crate::gameplay::enemy::EnemyDeathEvent;
+use crate::gameplay::level::RandomSeed;
+use crate::gameplay::loot::LootRoot;
+use crate::gameplay::loot::asset::LootAssets;
+use avian2d::debug_render::DebugRender;
+use avian2d::prelude::{
+    Collider, CollidingEntities, CollisionLayers, LockedAxes, RigidBody, Sensor,
+};
+use bevy::app::App;
+use bevy::color::palettes::tailwind::GREEN_500;
+use bevy::prelude::*;
+use rand::RngExt;
+use ron_asset_manager::Shandle;
+
+const DROP_OFFSET: Vec2 = Vec2::new(16.0, 0.0);
+
+#[derive(Component, Reflect, Default)]
+pub struct HealAmount(pub(crate) f32);
+
+pub fn plugin(app: &mut App) {
+    app.world_mut().add_observer(spawn_first_aid);
+}
+
+fn spawn_first_aid(
+    on: On<EnemyDeathEvent>,
+    root: Single<Entity, With<LootRoot>>,
+    loot_assets: Res<LootAssets>,
+    mut rng: ResMut<RandomSeed>,
+    mut commands: Commands,
+) {
+    if !rng.loot.random_bool(loot_assets.heal.chance) {
+        return;
+    }
+    let Some(mut root) = commands.get_entity(*root).ok() else {
+        return;
+    };
+    root.with_child(first_aid(
+        &loot_assets.heal.sprite,
+        loot_assets.heal.amount,
+        on.pos + DROP_OFFSET,
+    ));
+}
+
+pub fn first_aid(sprite: &Shandle<Image>, amount: f32, position: Vec2) -> impl Bundle {
+    (
+        Name::new("First Aid"),
+        HealAmount(amount),
+        GameLayer::Loot,
+        Sprite::from_image(sprite.handle.clone()),
+        Transform::from_translation(position.extend(1.0)).with_scale(Vec2::splat(2.0).extend(1.0)),
+        (
+            RigidBody::Static,
+            Collider::circle(10.),
+            Sensor,
+            LockedAxes::ROTATION_LOCKED,
+            CollisionLayers::new(GameLayer::Loot, [GameLayer::Player]),
+            CollidingEntities::default(),
+        ),
+        DebugRender::default().with_collider_color(GREEN_500.into()),
+    )
+}
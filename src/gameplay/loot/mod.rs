@@ -1,16 +1,20 @@
 use bevy::app::App;
 use bevy::prelude::*;
 
-mod asset;
+pub mod asset;
+mod chest;
+mod heal;
 mod xp;
 
-pub use xp::XpAmount;
+pub use chest::{ChestContents, RerollTokens, roll_upgrades};
+pub use heal::{HealAmount, first_aid};
+pub use xp::{XpAmount, xp_gem};
 
 #[derive(Component, Reflect, Default)]
 struct LootRoot;
 
 pub fn plugin(app: &mut App) {
-    app.add_plugins((asset::plugin, xp::plugin));
+    app.add_plugins((asset::plugin, chest::plugin, heal::plugin, xp::plugin));
 }
 
 pub fn loot_root() -> impl Bundle {
@@ -27,15 +27,15 @@ fn spawn_gem(
     let Some(mut root) = commands.get_entity(*root).ok() else {
         return;
     };
-    root.with_child(xp_gem(&loot_assets.xp, on.pos));
+    root.with_child(xp_gem(&loot_assets.xp, 2., on.pos));
 }
 
-fn xp_gem(sprite: &Shandle<Image>, position: Vec2) -> impl Bundle {
+pub fn xp_gem(sprite: &Shandle<Image>, amount: f32, position: Vec2) -> impl Bundle {
     let mut sprite = Sprite::from_image(sprite.handle.clone());
     sprite.color = Color::linear_rgb(10., 10., 10.);
     (
         Name::new("Xp Gem"),
-        XpAmount(2.),
+        XpAmount(amount),
         GameLayer::Loot,
         sprite,
         Transform::from_translation(position.extend(1.0)),
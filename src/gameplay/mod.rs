@@ -1,14 +1,24 @@
 use bevy::prelude::*;
 
-mod animation;
-mod enemy;
+pub mod achievement;
+pub mod animation;
+pub mod credits;
+pub mod daily;
+pub mod enemy;
+pub mod extraction;
 pub mod health;
+pub mod hit_stop;
 mod layer;
 pub mod level;
-mod loot;
+pub mod loot;
+pub mod modifier;
 mod movement;
 pub mod player;
-mod tilemap;
+pub mod replay;
+pub mod run;
+pub mod tilemap;
+pub mod upgrade;
+pub mod world_event;
 
 pub use layer::GameLayer;
 
@@ -19,9 +29,20 @@ pub fn plugin(app: &mut App) {
         movement::plugin,
         player::plugin,
         enemy::plugin,
+        extraction::plugin,
         tilemap::plugin,
         health::plugin,
+        hit_stop::plugin,
         layer::plugin,
         loot::plugin,
+        world_event::plugin,
+    ));
+    app.add_plugins((
+        achievement::plugin,
+        credits::plugin,
+        daily::plugin,
+        modifier::plugin,
+        replay::plugin,
+        run::plugin,
     ));
 }
@@ -0,0 +1,129 @@
+//! Rules changing a whole run, picked before it starts or imposed by the daily.
+
+use crate::asset_tracking::LoadResource;
+use crate::gameplay::enemy::asset::Enemy;
+use crate::gameplay::health::Health;
+use crate::gameplay::movement::MovementController;
+use crate::{AppSystems, PausableSystems};
+use bevy::prelude::*;
+use ron_asset_manager::prelude::RonAsset;
+use serde::{Deserialize, Serialize};
+
+pub fn plugin(app: &mut App) {
+    app.load_resource::<ModifierAssets>("modifiers.ron");
+    app.init_resource::<RunModifiers>();
+    app.add_systems(
+        FixedUpdate,
+        apply_enemy_modifiers
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems),
+    );
+}
+
+#[derive(Resource, Asset, RonAsset, TypePath, Deserialize, Debug, Clone)]
+pub struct ModifierAssets {
+    /// Offered in the pre-run menu.
+    pub mutators: Vec<ModifierSet>,
+}
+
+/// Named group of modifiers, picked as a whole.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ModifierSet {
+    pub name: String,
+    pub modifiers: Vec<Modifier>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub enum Modifier {
+    EnemyHealth(f32),
+    EnemySpeed(f32),
+    SpawnRate(f32),
+    XpGain(f32),
+    PlayerDamage(f32),
+    GlassCannon { damage: f32, health: f32 },
+    NoRegen,
+    NoLevelUp,
+    OneHitDeath,
+}
+
+impl Modifier {
+    pub fn description(&self) -> String {
+        match self {
+            Modifier::EnemyHealth(ratio) => format!("Enemy health x{ratio}"),
+            Modifier::EnemySpeed(ratio) => format!("Enemies move x{ratio} faster"),
+            Modifier::SpawnRate(ratio) => format!("Enemy waves x{ratio}"),
+            Modifier::XpGain(ratio) => format!("XP x{ratio}"),
+            Modifier::PlayerDamage(ratio) => format!("Damage x{ratio}"),
+            Modifier::GlassCannon { damage, health } => {
+                format!("Damage x{damage}, health x{health}")
+            }
+            Modifier::NoRegen => "No health regeneration".to_string(),
+            Modifier::NoLevelUp => "No level-up upgrades".to_string(),
+            Modifier::OneHitDeath => "Any hit is deadly".to_string(),
+        }
+    }
+}
+
+/// Rules of the current run, inserted by `spawn_level`.
+#[derive(Resource, Debug, Clone)]
+pub struct RunModifiers {
+    pub enemy_health: f32,
+    pub enemy_speed: f32,
+    pub spawn_rate: f32,
+    pub xp_gain: f32,
+    pub player_damage: f32,
+    pub player_health: f32,
+    pub regeneration: bool,
+    /// Levels are still gained, without choosing an upgrade.
+    pub level_up_menu: bool,
+    pub one_hit_death: bool,
+}
+
+impl Default for RunModifiers {
+    fn default() -> Self {
+        Self {
+            enemy_health: 1.,
+            enemy_speed: 1.,
+            spawn_rate: 1.,
+            xp_gain: 1.,
+            player_damage: 1.,
+            player_health: 1.,
+            regeneration: true,
+            level_up_menu: true,
+            one_hit_death: false,
+        }
+    }
+}
+
+impl RunModifiers {
+    pub fn new<'a>(modifiers: impl IntoIterator<Item = &'a Modifier>) -> Self {
+        let mut rules = Self::default();
+        for modifier in modifiers {
+            match *modifier {
+                Modifier::EnemyHealth(ratio) => rules.enemy_health *= ratio,
+                Modifier::EnemySpeed(ratio) => rules.enemy_speed *= ratio,
+                Modifier::SpawnRate(ratio) => rules.spawn_rate *= ratio,
+                Modifier::XpGain(ratio) => rules.xp_gain *= ratio,
+                Modifier::PlayerDamage(ratio) => rules.player_damage *= ratio,
+                Modifier::GlassCannon { damage, health } => {
+                    rules.player_damage *= damage;
+                    rules.player_health *= health;
+                }
+                Modifier::NoRegen => rules.regeneration = false,
+                Modifier::NoLevelUp => rules.level_up_menu = false,
+                Modifier::OneHitDeath => rules.one_hit_death = true,
+            }
+        }
+        rules
+    }
+}
+
+fn apply_enemy_modifiers(
+    modifiers: Res<RunModifiers>,
+    mut enemies: Query<(&mut Health, &mut MovementController), Added<Enemy>>,
+) {
+    for (mut health, mut movement) in &mut enemies {
+        *health = Health::new(health.max * modifiers.enemy_health);
+        movement.max_speed *= modifiers.enemy_speed;
+    }
+}
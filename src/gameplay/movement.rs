@@ -5,9 +5,11 @@ use crate::{AppSystems, PausableSystems};
 
 pub fn plugin(app: &mut App) {
     app.add_systems(
-        Update,
-        apply_movement
-            .in_set(AppSystems::Update)
+        FixedUpdate,
+        (
+            update_movement_override.in_set(AppSystems::TickTimers),
+            apply_movement.in_set(AppSystems::Update),
+        )
             .in_set(PausableSystems),
     );
 }
@@ -29,10 +31,44 @@ impl Default for MovementController {
     }
 }
 
+/// Keeps the current [`LinearVelocity`] untouched by the [`MovementController`] until the timer ends.
+#[derive(Component, Reflect)]
+pub struct MovementOverride {
+    pub timer: Timer,
+}
+
+impl MovementOverride {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+        }
+    }
+}
+
+fn update_movement_override(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut overrides: Query<(
+        Entity,
+        &mut MovementOverride,
+        Option<&mut MovementController>,
+    )>,
+) {
+    for (entity, mut movement_override, controller) in &mut overrides {
+        movement_override.timer.tick(time.delta());
+        if movement_override.timer.just_finished() {
+            commands.entity(entity).remove::<MovementOverride>();
+            if let Some(mut controller) = controller {
+                controller.set_changed();
+            }
+        }
+    }
+}
+
 fn apply_movement(
     mut movement_query: Query<
         (&MovementController, &mut LinearVelocity),
-        Changed<MovementController>,
+        (Changed<MovementController>, Without<MovementOverride>),
     >,
 ) {
     for (controller, mut linear_velocity) in &mut movement_query {
@@ -10,13 +10,71 @@ pub fn plugin(app: &mut App) {
 
 #[derive(Resource, Asset, RonAsset, TypePath, Deserialize, Debug, Clone)]
 pub struct PlayerAssets {
+    #[asset]
+    pub characters: Vec<CharacterType>,
+    /// Seconds during which the player ignores enemy damage after being hit.
+    pub invulnerability: f32,
+    pub dash: DashSettings,
+    #[asset]
+    pub pickup_xp: Shandle<AudioSource>,
+    #[asset]
+    pub pickup_heal: Shandle<AudioSource>,
+    #[asset]
+    pub hurt_sound: Shandle<AudioSource>,
+}
+
+impl PlayerAssets {
+    /// Falls back to the first character when the index is out of the roster.
+    pub fn character(&self, index: usize) -> &CharacterType {
+        self.characters.get(index).unwrap_or(&self.characters[0])
+    }
+}
+
+#[derive(RonAsset, Deserialize, Debug, Clone)]
+pub struct CharacterType {
     pub name: String,
     pub max_speed: f32,
     pub max_health: f32,
+    /// Health points restored per second.
+    pub regen: f32,
     pub auto_aim_angle: f32,
     pub fire_origin: Vec2,
+    /// Name of the weapon from weapon.ron filling the first slot.
+    pub weapon: String,
+    pub passive: Passive,
     #[asset]
     pub sprite: Shandle<Animation>,
-    #[asset]
-    pub pickup_xp: Shandle<AudioSource>,
+    /// Displayed size of one animation frame, in pixels.
+    pub sprite_size: Vec2,
+}
+
+/// Innate bonus of a character, applied once when the player is spawned.
+#[derive(Deserialize, Debug, Copy, Clone)]
+pub enum Passive {
+    Damage(f32),
+    FireRate(f32),
+    MaxSpeed(f32),
+    MaxHealth(f32),
+    Regen(f32),
+}
+
+impl Passive {
+    pub fn description(&self) -> String {
+        let percent = |ratio: f32| (ratio * 100.0).round();
+        match self {
+            Passive::Damage(ratio) => format!("+{}% damage", percent(*ratio)),
+            Passive::FireRate(ratio) => format!("+{}% fire rate", percent(*ratio)),
+            Passive::MaxSpeed(ratio) => format!("+{}% speed", percent(*ratio)),
+            Passive::MaxHealth(ratio) => format!("+{}% max health", percent(*ratio)),
+            Passive::Regen(amount) => format!("+{amount} HP/s"),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Copy, Clone)]
+pub struct DashSettings {
+    pub speed: f32,
+    /// Seconds during which the dash velocity is kept and enemies are ignored.
+    pub duration: f32,
+    pub cooldown: f32,
 }
@@ -0,0 +1,130 @@
+use crate::gameplay::layer::GameLayer;
+use crate::gameplay::movement::{MovementController, MovementOverride};
+use crate::gameplay::player::Player;
+use crate::gameplay::player::asset::{DashSettings, PlayerAssets};
+use crate::gameplay::player::health::Invulnerable;
+use crate::{AppSystems, PausableSystems};
+use avian2d::prelude::{CollisionLayers, LinearVelocity};
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(
+        FixedUpdate,
+        (
+            update_dash_timers.in_set(AppSystems::TickTimers),
+            apply_dash.in_set(AppSystems::Update),
+        )
+            .in_set(PausableSystems),
+    );
+    app.add_systems(
+        Update,
+        record_dash_input
+            .in_set(AppSystems::RecordInput)
+            .in_set(PausableSystems),
+    );
+}
+
+const DASH_KEY: KeyCode = KeyCode::Space;
+const DASH_BUTTON: GamepadButton = GamepadButton::South;
+
+#[derive(Component, Reflect)]
+pub struct Dash {
+    pub cooldown: Timer,
+    /// Set by the input, consumed by the next fixed tick.
+    pub requested: bool,
+}
+
+impl Dash {
+    pub fn new(settings: &DashSettings) -> Self {
+        let mut cooldown = Timer::from_seconds(settings.cooldown, TimerMode::Once);
+        cooldown.finish();
+        Self {
+            cooldown,
+            requested: false,
+        }
+    }
+}
+
+#[derive(Component, Reflect)]
+struct Dashing {
+    timer: Timer,
+}
+
+fn update_dash_timers(
+    mut commands: Commands,
+    time: Res<Time>,
+    player: Single<(Entity, &mut Dash, Option<&mut Dashing>, &CollisionLayers), With<Player>>,
+) {
+    let (entity, mut dash, dashing, layers) = player.into_inner();
+    dash.cooldown.tick(time.delta());
+    let Some(mut dashing) = dashing else {
+        return;
+    };
+    dashing.timer.tick(time.delta());
+    if dashing.timer.just_finished() {
+        let mut layers = *layers;
+        layers.filters.add(GameLayer::Enemy);
+        commands.entity(entity).insert(layers).remove::<Dashing>();
+    }
+}
+
+fn record_dash_input(
+    input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut dash: Single<&mut Dash, With<Player>>,
+) {
+    let gamepad_pressed = gamepads
+        .iter()
+        .next()
+        .is_some_and(|gamepad| gamepad.just_pressed(DASH_BUTTON));
+    if input.just_pressed(DASH_KEY) || gamepad_pressed {
+        dash.requested = true;
+    }
+}
+
+fn apply_dash(
+    mut commands: Commands,
+    player_assets: Res<PlayerAssets>,
+    player: Single<
+        (
+            Entity,
+            &mut Dash,
+            &MovementController,
+            &mut LinearVelocity,
+            &CollisionLayers,
+            Option<&Invulnerable>,
+        ),
+        With<Player>,
+    >,
+) {
+    let (entity, mut dash, controller, mut velocity, layers, invulnerable) = player.into_inner();
+    if !dash.requested {
+        return;
+    }
+    dash.requested = false;
+
+    let Ok(direction) = Dir2::new(controller.direction) else {
+        return;
+    };
+    if !dash.cooldown.is_finished() {
+        return;
+    }
+
+    let settings = player_assets.dash;
+    dash.cooldown.reset();
+    velocity.0 = direction * settings.speed;
+    let mut layers = *layers;
+    layers.filters.remove(GameLayer::Enemy);
+
+    let mut player = commands.entity(entity);
+    player.insert((
+        layers,
+        MovementOverride::new(settings.duration),
+        Dashing {
+            timer: Timer::from_seconds(settings.duration, TimerMode::Once),
+        },
+    ));
+    if invulnerable.is_none_or(|i| i.timer.remaining_secs() < settings.duration) {
+        player.insert(Invulnerable::new(settings.duration));
+    }
+}
@@ -1,7 +1,14 @@
+use crate::audio::{AudioSettings, sound_fx};
 use crate::gameplay::enemy::Hurt;
 use crate::gameplay::enemy::asset::{Damage, DamageCooldown, Enemy};
 use crate::gameplay::health::Health;
+use crate::gameplay::level::RandomSeed;
+use crate::gameplay::loot::HealAmount;
+use crate::gameplay::modifier::RunModifiers;
 use crate::gameplay::player::Player;
+use crate::gameplay::player::asset::PlayerAssets;
+use crate::gameplay::run::RunStats;
+use crate::hud::spawn_heal_popup;
 use crate::menu::Menu;
 use crate::{AppSystems, PausableSystems};
 use avian2d::prelude::CollidingEntities;
@@ -10,13 +17,44 @@ use bevy::prelude::*;
 
 pub fn plugin(app: &mut App) {
     app.add_systems(
-        Update,
+        FixedUpdate,
         (
-            update_cooldown.in_set(AppSystems::TickTimers),
-            (apply_damage, check_death).in_set(AppSystems::Update),
+            (update_cooldown, update_invulnerability).in_set(AppSystems::TickTimers),
+            (apply_damage, apply_heal, check_death).in_set(AppSystems::Update),
         )
             .in_set(PausableSystems),
     );
+    app.add_systems(
+        Update,
+        flash_when_invulnerable
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems),
+    );
+}
+
+#[derive(Event)]
+pub struct PlayerHurt;
+
+#[derive(Component, Reflect)]
+pub struct Invulnerable {
+    pub timer: Timer,
+    pub flash: bool,
+}
+
+impl Invulnerable {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+            flash: false,
+        }
+    }
+
+    fn hurt(duration: f32) -> Self {
+        Self {
+            flash: true,
+            ..Self::new(duration)
+        }
+    }
 }
 
 fn update_cooldown(
@@ -32,20 +70,84 @@ fn update_cooldown(
     }
 }
 
+fn update_invulnerability(
+    mut commands: Commands,
+    time: Res<Time>,
+    player: Single<(Entity, &mut Invulnerable, &mut Sprite), With<Player>>,
+) {
+    let (entity, mut invulnerable, mut sprite) = player.into_inner();
+    invulnerable.timer.tick(time.delta());
+    if invulnerable.timer.just_finished() {
+        sprite.color = Color::WHITE;
+        commands.entity(entity).remove::<Invulnerable>();
+    }
+}
+
 fn apply_damage(
     mut commands: Commands,
-    player: Single<(&mut Health, &CollidingEntities), With<Player>>,
+    player: Single<(Entity, &mut Health, &CollidingEntities, Has<Invulnerable>), With<Player>>,
     enemies: Query<&Damage, (With<Enemy>, Without<DamageCooldown>, Without<Hurt>)>,
+    player_assets: Res<PlayerAssets>,
+    audio_settings: Res<AudioSettings>,
+    modifiers: Res<RunModifiers>,
+    mut stats: ResMut<RunStats>,
 ) {
-    let (mut health, colliding_entities) = player.into_inner();
+    let (player, mut health, colliding_entities, invulnerable) = player.into_inner();
+    if invulnerable {
+        return;
+    }
+    let Some((e, damage)) = colliding_entities
+        .iter()
+        .find_map(|e| enemies.get(*e).ok().map(|damage| (*e, damage)))
+    else {
+        return;
+    };
+
+    let amount = if modifiers.one_hit_death {
+        health.current
+    } else {
+        damage.damage
+    };
+    health.damage(amount);
+    stats.damage_taken += amount;
+    commands.entity(e).insert(DamageCooldown {
+        timer: Timer::from_seconds(damage.cooldown, TimerMode::Once),
+    });
+    commands
+        .entity(player)
+        .insert(Invulnerable::hurt(player_assets.invulnerability));
+    commands.spawn(sound_fx(
+        player_assets.hurt_sound.handle.clone(),
+        &audio_settings,
+    ));
+    commands.trigger(PlayerHurt);
+}
+
+fn apply_heal(
+    mut commands: Commands,
+    player: Single<(&mut Health, &CollidingEntities, &GlobalTransform), With<Player>>,
+    heal_pickups: Query<&HealAmount>,
+    player_assets: Res<PlayerAssets>,
+    audio_settings: Res<AudioSettings>,
+    mut rng: ResMut<RandomSeed>,
+) {
+    let (mut health, colliding_entities, transform) = player.into_inner();
     for e in colliding_entities.iter() {
-        let Ok(damage) = enemies.get(*e) else {
+        let Ok(amount) = heal_pickups.get(*e) else {
             continue;
         };
-        health.current -= damage.damage;
-        commands.entity(*e).insert(DamageCooldown {
-            timer: Timer::from_seconds(damage.cooldown, TimerMode::Once),
-        });
+        let healed = health.heal(amount.0);
+        spawn_heal_popup(
+            &mut commands,
+            &mut rng.effects,
+            transform.translation(),
+            healed,
+        );
+        commands.entity(*e).despawn();
+        commands.spawn(sound_fx(
+            player_assets.pickup_heal.handle.clone(),
+            &audio_settings,
+        ));
     }
 }
 
@@ -57,3 +159,19 @@ fn check_death(
         next.set(Menu::GameOver);
     }
 }
+
+fn flash_when_invulnerable(player: Single<(&Invulnerable, &mut Sprite), With<Player>>) {
+    const BLINK_PERIOD: f32 = 0.1;
+    let (invulnerable, mut sprite) = player.into_inner();
+    if !invulnerable.flash {
+        return;
+    }
+    let elapsed = invulnerable.timer.elapsed_secs();
+    let red = 1.0 - invulnerable.timer.fraction();
+    let alpha = if ((elapsed / BLINK_PERIOD) as u32).is_multiple_of(2) {
+        1.0
+    } else {
+        0.4
+    };
+    sprite.color = Color::linear_rgba(1.0 + red * 4.0, 1.0 - red, 1.0 - red, alpha);
+}
@@ -1,10 +1,13 @@
-use crate::gameplay::health::Health;
+use crate::gameplay::health::{Health, Regeneration};
 use crate::gameplay::layer::GameLayer;
-use crate::gameplay::player::asset::PlayerAssets;
+use crate::gameplay::modifier::RunModifiers;
+use crate::gameplay::player::asset::{CharacterType, Passive, PlayerAssets};
 use crate::gameplay::player::weapon::{
-    WeaponAssets, WeaponDirection, aim_zone, fire_origin, weapon_slots,
+    WeaponAssets, WeaponDirection, WeaponSlots, aim_zone, fire_origin, weapon_slots,
 };
 use crate::gameplay::{animation::CharacterAnimation, movement::MovementController};
+use crate::hud::minimap::MinimapMarker;
+use crate::profile::shop::MetaBonuses;
 use avian2d::prelude::{
     CenterOfMass, Collider, CollidingEntities, CollisionLayers, DebugRender, LinearVelocity,
     LockedAxes, Mass, RigidBody, Sensor,
@@ -14,17 +17,21 @@ use bevy::prelude::*;
 use bevy::sprite::Anchor;
 
 pub mod asset;
+mod dash;
 mod health;
 mod movement;
 pub mod weapon;
 mod xp;
 
 use crate::gameplay::animation::Animation;
+pub use dash::Dash;
+pub use health::PlayerHurt;
 pub use xp::{LevelUp, Xp};
 
 pub fn plugin(app: &mut App) {
     app.add_plugins((
         asset::plugin,
+        dash::plugin,
         movement::plugin,
         weapon::plugin,
         health::plugin,
@@ -32,52 +39,111 @@ pub fn plugin(app: &mut App) {
     ));
 }
 
+/// Unlocked weapon slots before meta upgrades.
+const STARTING_WEAPON_SLOTS: usize = 2;
+
 pub fn spawn_player(
     commands: &mut RelatedSpawnerCommands<ChildOf>,
     player_assets: &PlayerAssets,
+    character: &CharacterType,
+    bonuses: &MetaBonuses,
+    modifiers: &RunModifiers,
     weapon_assets: &WeaponAssets,
     meshes: &mut Assets<Mesh>,
     materials: &mut Assets<ColorMaterial>,
     animations: &mut Assets<Animation>,
     texture_atlas_layouts: &mut Assets<TextureAtlasLayout>,
 ) {
+    let mut stats = PlayerStats {
+        health: Health::new(character.max_health + bonuses.max_health),
+        movement: MovementController {
+            max_speed: character.max_speed,
+            ..default()
+        },
+        regeneration: Regeneration(character.regen),
+        slots: WeaponSlots::starting(
+            weapon_assets,
+            &character.weapon,
+            STARTING_WEAPON_SLOTS + bonuses.weapon_slots,
+        ),
+    };
+    stats.apply_passive(character.passive);
+    stats.apply_modifiers(modifiers);
+
     commands
-        .spawn(player(player_assets, animations, texture_atlas_layouts))
+        .spawn(player(
+            player_assets,
+            character,
+            stats.health,
+            stats.movement,
+            stats.regeneration,
+            animations,
+            texture_atlas_layouts,
+        ))
         .with_children(|player| {
             player.spawn(aim_zone(
                 meshes,
                 materials,
-                player_assets.fire_origin,
-                player_assets.auto_aim_angle,
+                character.fire_origin,
+                character.auto_aim_angle,
             ));
-            player.spawn(weapon_slots(weapon_assets));
-            player.spawn(fire_origin(player_assets.fire_origin));
+            player.spawn(weapon_slots(stats.slots));
+            player.spawn(fire_origin(character.fire_origin));
         });
 }
 
+/// Starting values of the player, before any level-up.
+struct PlayerStats {
+    health: Health,
+    movement: MovementController,
+    regeneration: Regeneration,
+    slots: WeaponSlots,
+}
+
+impl PlayerStats {
+    fn apply_passive(&mut self, passive: Passive) {
+        match passive {
+            Passive::Damage(ratio) => self.slots.iter_mut().for_each(|w| w.inc_damage(ratio)),
+            Passive::FireRate(ratio) => self.slots.iter_mut().for_each(|w| w.inc_fire_rate(ratio)),
+            Passive::MaxSpeed(ratio) => self.movement.max_speed += self.movement.max_speed * ratio,
+            Passive::MaxHealth(ratio) => self.health.inc_max_health(ratio),
+            Passive::Regen(amount) => self.regeneration.0 += amount,
+        }
+    }
+
+    fn apply_modifiers(&mut self, modifiers: &RunModifiers) {
+        let damage = modifiers.player_damage - 1.;
+        self.slots.iter_mut().for_each(|w| w.inc_damage(damage));
+        self.health = Health::new(self.health.max * modifiers.player_health);
+        if !modifiers.regeneration {
+            self.regeneration.0 = 0.;
+        }
+    }
+}
+
 fn player(
     player_assets: &PlayerAssets,
+    character: &CharacterType,
+    health: Health,
+    movement: MovementController,
+    regeneration: Regeneration,
     animations: &mut Assets<Animation>,
     texture_atlas_layouts: &mut Assets<TextureAtlasLayout>,
 ) -> impl Bundle {
-    let (sprite, animation) =
-        CharacterAnimation::init(animations, texture_atlas_layouts, &player_assets.sprite);
+    let (mut sprite, animation) =
+        CharacterAnimation::init(animations, texture_atlas_layouts, &character.sprite);
+    sprite.custom_size = Some(character.sprite_size);
 
     (
-        Name::new(player_assets.name.to_string()),
+        Name::new(character.name.to_string()),
         Player,
-        Health::new(player_assets.max_health),
+        MinimapMarker::Player,
+        health,
+        regeneration,
         GameLayer::Player,
         Anchor(Vec2::new(0., -0.1)),
         Transform::default(),
-        (
-            sprite,
-            animation,
-            MovementController {
-                max_speed: player_assets.max_speed,
-                ..default()
-            },
-        ),
+        (sprite, animation, movement),
         (
             RigidBody::Dynamic,
             Collider::capsule(12., 70.),
@@ -86,10 +152,14 @@ fn player(
             Sensor,
             LinearVelocity::ZERO,
             LockedAxes::ROTATION_LOCKED,
-            CollisionLayers::new(GameLayer::Player, [GameLayer::Loot, GameLayer::Enemy]),
+            CollisionLayers::new(
+                GameLayer::Player,
+                [GameLayer::Loot, GameLayer::Enemy, GameLayer::Zone],
+            ),
             CollidingEntities::default(),
         ),
         WeaponDirection(Dir2::X),
+        Dash::new(&player_assets.dash),
         DebugRender::default().with_collider_color(Color::WHITE),
     )
 }
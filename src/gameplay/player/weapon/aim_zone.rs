@@ -8,7 +8,7 @@ use std::f32::consts::FRAC_PI_2;
 
 pub fn plugin(app: &mut App) {
     app.add_systems(
-        Update,
+        FixedUpdate,
         update_aim_zone
             .in_set(AppSystems::Update)
             .in_set(PausableSystems),
@@ -14,6 +14,15 @@ pub fn plugin(app: &mut App) {
 pub struct WeaponAssets {
     #[asset]
     pub types: Vec<WeaponType>,
+    #[serde(default)]
+    pub hit_stop: Option<HitStopSettings>,
+}
+
+/// Freezes the game for `duration` seconds when a single hit deals at least `min_damage`.
+#[derive(Deserialize, Debug, Copy, Clone)]
+pub struct HitStopSettings {
+    pub min_damage: f32,
+    pub duration: f32,
 }
 
 #[derive(Deserialize, RonAsset, TypePath, Debug, Clone, Default)]
@@ -31,6 +40,8 @@ pub struct WeaponStats {
     pub speed: f32,
     pub fire_rate: f32,
     pub lifetime: f32,
+    #[serde(default)]
+    pub knockback: f32,
 }
 
 impl WeaponStats {
@@ -40,6 +51,7 @@ impl WeaponStats {
             speed: self.speed + (self.speed * upgrade.speed),
             fire_rate: self.fire_rate - (self.fire_rate * upgrade.fire_rate),
             lifetime: self.lifetime + (self.lifetime * upgrade.lifetime),
+            knockback: self.knockback + (self.knockback * upgrade.knockback),
         }
     }
 }
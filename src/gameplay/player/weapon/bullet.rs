@@ -1,7 +1,12 @@
 use crate::gameplay::enemy::asset::{Damage, Enemy};
 use crate::gameplay::health::Health;
+use crate::gameplay::hit_stop::HitStop;
 use crate::gameplay::layer::GameLayer;
+use crate::gameplay::level::RandomSeed;
+use crate::gameplay::movement::MovementOverride;
+use crate::gameplay::player::weapon::WeaponAssets;
 use crate::gameplay::player::weapon::slot::Weapon;
+use crate::gameplay::run::RunStats;
 use crate::hud::spawn_damage_popup;
 use crate::{AppSystems, PausableSystems};
 use avian2d::math::PI;
@@ -12,7 +17,7 @@ use ron_asset_manager::Shandle;
 
 pub fn plugin(app: &mut App) {
     app.add_systems(
-        Update,
+        FixedUpdate,
         (apply_damage, despawn_bullets)
             .in_set(AppSystems::Update)
             .in_set(PausableSystems),
@@ -28,16 +33,29 @@ pub struct FireOrigin;
 #[derive(Component)]
 struct Bullet;
 
+#[derive(Component)]
+struct Knockback(f32);
+
+/// Name of the weapon that fired the bullet.
+#[derive(Component)]
+struct FiredBy(String);
+
+const KNOCKBACK_DURATION: f32 = 0.15;
+
 impl Weapon {
     pub fn bullet(&self, from: Vec2, direction: Dir2) -> Option<impl Bundle> {
         let stats = self.stats();
-        Some(bullet(
-            &self.weapon.bullet,
-            stats.damage,
-            stats.speed,
-            stats.lifetime,
-            from,
-            direction,
+        Some((
+            bullet(
+                &self.weapon.bullet,
+                stats.damage,
+                stats.speed,
+                stats.lifetime,
+                stats.knockback,
+                from,
+                direction,
+            ),
+            FiredBy(self.weapon.name.clone()),
         ))
     }
 }
@@ -72,6 +90,7 @@ pub fn bullet(
     damage: f32,
     speed: f32,
     lifetime: f32,
+    knockback: f32,
     from: Vec2,
     direction: Dir2,
 ) -> impl Bundle {
@@ -82,6 +101,7 @@ pub fn bullet(
         Bullet,
         GameLayer::Bullet,
         BulletLifetime::new(lifetime),
+        Knockback(knockback),
         Damage {
             damage,
             cooldown: 0.,
@@ -107,19 +127,55 @@ pub fn bullet(
 
 fn apply_damage(
     mut commands: Commands,
-    bullets: Query<(Entity, &Damage, &CollidingEntities), With<Bullet>>,
-    mut enemies: Query<(&mut Health, &GlobalTransform), With<Enemy>>,
+    bullets: Query<
+        (
+            Entity,
+            &Damage,
+            &Knockback,
+            &FiredBy,
+            &LinearVelocity,
+            &CollidingEntities,
+        ),
+        With<Bullet>,
+    >,
+    mut enemies: Query<
+        (&mut Health, &mut LinearVelocity, &GlobalTransform),
+        (With<Enemy>, Without<Bullet>),
+    >,
+    weapon_assets: Res<WeaponAssets>,
+    mut hit_stop: ResMut<HitStop>,
+    mut stats: ResMut<RunStats>,
+    mut rng: ResMut<RandomSeed>,
 ) {
-    for (bullet, damage, colliding_entities) in bullets {
+    for (bullet, damage, knockback, fired_by, bullet_velocity, colliding_entities) in bullets {
         if colliding_entities.is_empty() {
             continue;
         }
+        let direction = bullet_velocity.0.normalize_or_zero();
         for e in colliding_entities.iter() {
-            let Ok((mut health, t)) = enemies.get_mut(*e) else {
+            let Ok((mut health, mut velocity, t)) = enemies.get_mut(*e) else {
                 continue;
             };
-            health.current -= damage.damage;
-            spawn_damage_popup(&mut commands, t.translation(), damage.damage);
+            health.damage(damage.damage);
+            stats.deal_damage(&fired_by.0, damage.damage);
+            spawn_damage_popup(
+                &mut commands,
+                &mut rng.effects,
+                t.translation(),
+                damage.damage,
+            );
+
+            if knockback.0 > 0.0 {
+                velocity.0 += direction * knockback.0;
+                commands
+                    .entity(*e)
+                    .insert(MovementOverride::new(KNOCKBACK_DURATION));
+            }
+            if let Some(settings) = weapon_assets.hit_stop
+                && damage.damage >= settings.min_damage
+            {
+                hit_stop.trigger(settings.duration);
+            }
         }
 
         commands.entity(bullet).despawn();
@@ -9,17 +9,18 @@ mod bullet;
 mod slot;
 
 use crate::audio::{AudioSettings, sound_fx};
+use crate::gameplay::level::RandomSeed;
 use crate::gameplay::player::weapon::aim_zone::AimZone;
 use crate::gameplay::player::weapon::bullet::FireOrigin;
 pub use aim_zone::aim_zone;
 pub use asset::WeaponAssets;
 pub use bullet::{BulletRoot, bullet_root, fire_origin};
-pub use slot::{WeaponSlots, weapon_slots};
+pub use slot::{Weapon, WeaponSlots, weapon_slots};
 
 pub fn plugin(app: &mut App) {
     app.add_plugins((asset::plugin, aim_zone::plugin, bullet::plugin));
     app.add_systems(
-        Update,
+        FixedUpdate,
         (
             update_timers.in_set(AppSystems::TickTimers),
             auto_fire.in_set(AppSystems::Update),
@@ -43,6 +44,7 @@ fn auto_fire(
     aim_zone: Single<&CollidingEntities, With<AimZone>>,
     enemies: Query<&GlobalTransform>,
     audio_settings: Res<AudioSettings>,
+    mut rng: ResMut<RandomSeed>,
 ) {
     let Some(mut root) = commands.get_entity(*root).ok() else {
         return;
@@ -74,7 +76,7 @@ fn auto_fire(
             let sound = weapon
                 .weapon
                 .trigger_sounds
-                .choose(&mut rand::rng())
+                .choose(&mut rng.effects)
                 .unwrap();
             parent.spawn(sound_fx(sound.handle.clone(), &audio_settings));
         }
@@ -6,9 +6,44 @@ use std::time::Duration;
 #[derive(Component, Debug, Clone, Reflect)]
 pub struct WeaponSlots {
     pub slots: Vec<Weapon>,
+    /// Number of unlocked slots.
+    pub capacity: usize,
 }
 
 impl WeaponSlots {
+    /// Falls back to the first weapon when `weapon_name` is not in weapon.ron.
+    pub fn starting(weapon_assets: &WeaponAssets, weapon_name: &str, capacity: usize) -> Self {
+        let weapon = weapon_assets
+            .types
+            .iter()
+            .find(|weapon| weapon.name == weapon_name)
+            .or(weapon_assets.types.first())
+            .unwrap();
+        Self {
+            slots: vec![Weapon::new(weapon.clone())],
+            capacity,
+        }
+    }
+
+    /// Weapon offered for a free slot: one not carried yet, else the types in turn.
+    pub fn next_weapon(&self, weapon_assets: &WeaponAssets) -> Option<usize> {
+        if self.slots.len() >= self.capacity || weapon_assets.types.is_empty() {
+            return None;
+        }
+        let carried = |index: &usize| {
+            self.slots
+                .iter()
+                .any(|slot| slot.weapon.name == weapon_assets.types[*index].name)
+        };
+        (0..weapon_assets.types.len())
+            .find(|index| !carried(index))
+            .or(Some(self.slots.len() % weapon_assets.types.len()))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Weapon> {
+        self.slots.iter_mut()
+    }
+
     pub fn tick(&mut self, delta: Duration) {
         for weapon in self.slots.iter_mut() {
             weapon.timer.tick(delta);
@@ -60,12 +95,6 @@ impl Weapon {
     }
 }
 
-pub fn weapon_slots(weapon_assets: &WeaponAssets) -> impl Bundle {
-    let weapon = weapon_assets.types.first().unwrap();
-    (
-        Name::new("WeaponSlots"),
-        WeaponSlots {
-            slots: vec![Weapon::new(weapon.clone())],
-        },
-    )
+pub fn weapon_slots(slots: WeaponSlots) -> impl Bundle {
+    (Name::new("WeaponSlots"), slots)
 }
@@ -1,19 +1,20 @@
 use crate::audio::{AudioSettings, sound_fx};
 use crate::gameplay::loot::XpAmount;
+use crate::gameplay::modifier::RunModifiers;
 use crate::gameplay::player::Player;
 use crate::gameplay::player::asset::PlayerAssets;
+use crate::gameplay::run::RunStats;
 use crate::screen::Screen;
 use crate::{AppSystems, PausableSystems};
 use avian2d::prelude::CollidingEntities;
-use bevy::app::Update;
 use bevy::prelude::{
-    App, Commands, Component, Event, IntoScheduleConfigs, Query, Reflect, Res, Single, With,
-    in_state,
+    App, Commands, Component, Event, FixedUpdate, IntoScheduleConfigs, Query, Reflect, Res, ResMut,
+    Single, With, in_state,
 };
 
 pub fn plugin(app: &mut App) {
     app.add_systems(
-        Update,
+        FixedUpdate,
         (apply_xp.in_set(AppSystems::Update),)
             .in_set(PausableSystems)
             .run_if(in_state(Screen::Gameplay(false))),
@@ -29,13 +30,17 @@ fn apply_xp(
     xp_gems: Query<&XpAmount>,
     player_assets: Res<PlayerAssets>,
     audio_settings: Res<AudioSettings>,
+    modifiers: Res<RunModifiers>,
+    mut stats: ResMut<RunStats>,
 ) {
     let (mut xp, colliding_entities) = player.into_inner();
     for e in colliding_entities.iter() {
         let Ok(amount) = xp_gems.get(*e) else {
             continue;
         };
-        if xp.add(amount.0) {
+        let amount = amount.0 * modifiers.xp_gain;
+        stats.xp_collected += amount;
+        if xp.add(amount) {
             commands.trigger(LevelUp);
         }
         commands.entity(*e).despawn();
@@ -0,0 +1,314 @@
+//! Player input of a run, one frame per fixed tick, saved with the run setup so the run can be
+//! watched again exactly.
+
+use crate::gameplay::extraction::ExtractionRequest;
+use crate::gameplay::movement::MovementController;
+use crate::gameplay::player::weapon::WeaponDirection;
+use crate::gameplay::player::{Dash, Player};
+use crate::gameplay::run::RunSetup;
+use crate::gameplay::upgrade::Upgrade;
+use crate::menu::Menu;
+use crate::profile::shop::ShopAssets;
+use crate::profile::{Profile, storage};
+use crate::screen::Screen;
+use crate::{AppSystems, PausableSystems};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::TAU;
+
+const REPLAY_FILE: &str = "replay.ron";
+
+pub fn plugin(app: &mut App) {
+    // Live devices are ignored while a replay feeds the input.
+    app.configure_sets(
+        Update,
+        AppSystems::RecordInput.run_if(not(resource_exists::<Replay>)),
+    );
+    app.add_systems(
+        OnEnter(Screen::Gameplay(false)),
+        start_recording.run_if(not(resource_exists::<Replay>)),
+    );
+    app.add_systems(
+        FixedUpdate,
+        (
+            record_input.run_if(resource_exists::<Recording>),
+            replay_input.run_if(resource_exists::<Replay>),
+        )
+            .in_set(AppSystems::RecordInput)
+            .in_set(PausableSystems),
+    );
+    app.add_systems(
+        Update,
+        replay_choice.run_if(
+            resource_exists::<Replay>
+                .and_then(in_state(Menu::LevelUp).or_else(in_state(Menu::Chest))),
+        ),
+    );
+    app.add_observer(record_choice);
+    app.add_systems(
+        OnEnter(Menu::GameOver),
+        save_recording.run_if(resource_exists::<Recording>),
+    );
+    app.add_systems(
+        OnEnter(Menu::ExtractionSuccess),
+        save_recording.run_if(resource_exists::<Recording>),
+    );
+    app.add_systems(OnExit(Screen::Gameplay(false)), stop);
+}
+
+/// Input consumed by one fixed tick, quantized so a replay reads back exactly what was played.
+#[derive(Serialize, Deserialize, Debug, Default, Copy, Clone, Eq, PartialEq)]
+struct InputFrame {
+    /// Movement intent, each axis in -127..=127.
+    movement: (i8, i8),
+    /// Weapon direction, in 65536ths of a turn.
+    aim: u16,
+    dash: bool,
+    extraction: bool,
+}
+
+impl InputFrame {
+    fn new(movement: Vec2, aim: Dir2, dash: bool, extraction: bool) -> Self {
+        let axis = |value: f32| (value.clamp(-1., 1.) * 127.).round() as i8;
+        let turn = aim.to_angle().rem_euclid(TAU) / TAU;
+        Self {
+            movement: (axis(movement.x), axis(movement.y)),
+            aim: (turn * 65536.).round() as u32 as u16,
+            dash,
+            extraction,
+        }
+    }
+
+    fn apply(
+        &self,
+        controller: &mut MovementController,
+        direction: &mut WeaponDirection,
+        dash: &mut Dash,
+        extraction: &mut ExtractionRequest,
+    ) {
+        controller.direction = Vec2::new(self.movement.0 as f32, self.movement.1 as f32) / 127.;
+        direction.0 = Dir2::from_angle(self.aim as f32 / 65536. * TAU);
+        dash.requested = self.dash;
+        extraction.0 = self.extraction;
+    }
+}
+
+/// Picked in a menu opened by the simulation, recorded so a replay makes the same picks.
+#[derive(Event, Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MenuChoice {
+    Upgrade(Upgrade),
+    SkipUpgrade,
+    CollectChest,
+    RerollChest,
+}
+
+impl MenuChoice {
+    /// Menu the choice is made in.
+    fn menu(&self) -> Menu {
+        match self {
+            Self::Upgrade(_) | Self::SkipUpgrade => Menu::LevelUp,
+            Self::CollectChest | Self::RerollChest => Menu::Chest,
+        }
+    }
+}
+
+/// Input of the current run, or of a saved one.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone)]
+pub struct Recording {
+    pub setup: RunSetup,
+    /// Runs of identical frames, with their length.
+    frames: Vec<(u32, InputFrame)>,
+    /// Menu choices, with the number of ticks played before them.
+    choices: Vec<(u32, MenuChoice)>,
+}
+
+impl Recording {
+    /// The last recorded run, if any.
+    pub fn load() -> Option<Self> {
+        storage::load(REPLAY_FILE).ok()
+    }
+
+    fn push(&mut self, frame: InputFrame) {
+        match self.frames.last_mut() {
+            Some((count, last)) if *last == frame => *count += 1,
+            _ => self.frames.push((1, frame)),
+        }
+    }
+
+    fn ticks(&self) -> u32 {
+        self.frames.iter().map(|(count, _)| count).sum()
+    }
+}
+
+/// Present while a recording is played back.
+#[derive(Resource, Debug)]
+pub struct Replay {
+    recording: Recording,
+    /// Index of the run of frames being played, and frames already played in it.
+    position: (usize, u32),
+    ticks: u32,
+    choices: usize,
+    /// Run setup restored once the replay ends.
+    previous: RunSetup,
+}
+
+impl Replay {
+    /// Plays the recording with its own setup, the current one coming back without the daily
+    /// and mutators afterwards.
+    pub fn start(recording: Recording, run_setup: &mut RunSetup) -> Self {
+        let previous = RunSetup {
+            daily: None,
+            mutators: vec![],
+            ..run_setup.clone()
+        };
+        *run_setup = recording.setup.clone();
+        Self {
+            recording,
+            position: (0, 0),
+            ticks: 0,
+            choices: 0,
+            previous,
+        }
+    }
+
+    /// Frame of the next tick, no input once the recording is over.
+    fn next_frame(&mut self) -> InputFrame {
+        let (run, played) = &mut self.position;
+        let Some((count, frame)) = self.recording.frames.get(*run).copied() else {
+            return InputFrame::default();
+        };
+        *played += 1;
+        if *played == count {
+            *run += 1;
+            *played = 0;
+        }
+        self.ticks += 1;
+        frame
+    }
+}
+
+fn start_recording(
+    mut commands: Commands,
+    run_setup: Res<RunSetup>,
+    shop_assets: Res<ShopAssets>,
+    profile: Res<Profile>,
+) {
+    commands.insert_resource(Recording {
+        setup: RunSetup {
+            bonuses: Some(shop_assets.bonuses(&profile)),
+            ..run_setup.clone()
+        },
+        frames: vec![],
+        choices: vec![],
+    });
+}
+
+/// Logs the input of the tick, which then runs on the quantized values like a replay would.
+fn record_input(
+    mut recording: ResMut<Recording>,
+    mut extraction: ResMut<ExtractionRequest>,
+    player: Single<(&mut MovementController, &mut WeaponDirection, &mut Dash), With<Player>>,
+) {
+    let (mut controller, mut direction, mut dash) = player.into_inner();
+    let frame = InputFrame::new(
+        controller.direction,
+        direction.0,
+        dash.requested,
+        extraction.0,
+    );
+    frame.apply(&mut controller, &mut direction, &mut dash, &mut extraction);
+    recording.push(frame);
+}
+
+fn replay_input(
+    mut replay: ResMut<Replay>,
+    mut extraction: ResMut<ExtractionRequest>,
+    player: Single<(&mut MovementController, &mut WeaponDirection, &mut Dash), With<Player>>,
+) {
+    let (mut controller, mut direction, mut dash) = player.into_inner();
+    let frame = replay.next_frame();
+    frame.apply(&mut controller, &mut direction, &mut dash, &mut extraction);
+}
+
+fn record_choice(on: On<MenuChoice>, recording: Option<ResMut<Recording>>) {
+    if let Some(mut recording) = recording {
+        let ticks = recording.ticks();
+        recording.choices.push((ticks, *on));
+    }
+}
+
+fn replay_choice(
+    mut commands: Commands,
+    mut replay: ResMut<Replay>,
+    menu: Res<State<Menu>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    let Some((ticks, choice)) = replay
+        .recording
+        .choices
+        .get(replay.choices)
+        .copied()
+        .filter(|(_, choice)| choice.menu() == *menu.get())
+    else {
+        error!(
+            "Replay out of sync: no recorded choice for {:?} after {} ticks, stopping",
+            menu.get(),
+            replay.ticks
+        );
+        next_screen.set(Screen::Title(true));
+        return;
+    };
+    if ticks != replay.ticks {
+        warn!(
+            "Replay out of sync: choice recorded after {ticks} ticks, made after {}",
+            replay.ticks
+        );
+    }
+    replay.choices += 1;
+    commands.trigger(choice);
+}
+
+fn save_recording(recording: Res<Recording>) {
+    storage::save_compact(REPLAY_FILE, &*recording);
+}
+
+fn stop(mut commands: Commands, replay: Option<Res<Replay>>, mut run_setup: ResMut<RunSetup>) {
+    commands.remove_resource::<Recording>();
+    commands.remove_resource::<Replay>();
+    if let Some(replay) = replay {
+        *run_setup = replay.previous.clone();
+    }
+    run_setup.bonuses = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headless::{TICKS_PER_SECOND, WorldState, headless_app, play, wait};
+
+    const SECONDS: usize = 20;
+
+    #[test]
+    fn replay_reproduces_the_run() {
+        let mut app = headless_app(42);
+        let keys = [KeyCode::KeyD, KeyCode::KeyW, KeyCode::KeyA, KeyCode::KeyS];
+        for key in keys.iter().cycle().take(SECONDS) {
+            play(
+                &mut app,
+                &[*key],
+                TICKS_PER_SECOND,
+                MenuChoice::Upgrade(Upgrade::Damage),
+            );
+        }
+        let played = WorldState::read(&mut app);
+        assert!(played.level > 1);
+        let recording = app.world().resource::<Recording>().clone();
+        assert!(!recording.choices.is_empty());
+
+        let mut app = headless_app(recording.setup.seed);
+        let replay = Replay::start(recording, &mut app.world_mut().resource_mut::<RunSetup>());
+        app.world_mut().insert_resource(replay);
+        wait(&mut app, SECONDS * TICKS_PER_SECOND);
+        assert_eq!(played, WorldState::read(&mut app));
+    }
+}
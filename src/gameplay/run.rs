@@ -0,0 +1,246 @@
+use crate::gameplay::credits::{Credits, Settlement};
+use crate::gameplay::daily::Daily;
+use crate::gameplay::level::LevelAssets;
+use crate::gameplay::modifier::{ModifierAssets, ModifierSet};
+use crate::gameplay::player::asset::PlayerAssets;
+use crate::gameplay::player::{Player, Xp};
+use crate::hud::elimination::EliminationCount;
+use crate::hud::timer::SurvivalTimer;
+use crate::menu::Menu;
+use crate::profile::Profile;
+use crate::profile::shop::{MetaBonuses, ShopAssets};
+use crate::screen::Screen;
+use crate::{AppSystems, PausableSystems};
+use bevy::prelude::*;
+use rand::Rng;
+use rand::rngs::ThreadRng;
+use serde::{Deserialize, Serialize};
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<RunSetup>();
+    app.init_resource::<RunStats>();
+    app.add_systems(
+        OnEnter(Screen::Gameplay(false)),
+        (start_extraction_countdown, reset_run_stats),
+    );
+    app.add_systems(
+        OnEnter(Menu::GameOver),
+        summarize_run(RunEnd::Death)
+            .in_set(Summary)
+            .after(Settlement),
+    );
+    app.add_systems(
+        OnEnter(Menu::ExtractionSuccess),
+        summarize_run(RunEnd::Extraction)
+            .in_set(Summary)
+            .after(Settlement),
+    );
+    app.add_systems(
+        FixedUpdate,
+        tick_extraction_countdown
+            .in_set(AppSystems::TickTimers)
+            .in_set(PausableSystems),
+    );
+}
+
+/// Choices made in the menus before a run starts.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RunSetup {
+    /// Index of the chosen character in `PlayerAssets::characters`.
+    pub character: usize,
+    /// Index of the chosen duration in `LevelAssets::extraction_durations`.
+    pub extraction: usize,
+    pub seed: u32,
+    /// Set when playing the daily challenge, which fixes the seed and the modifiers.
+    pub daily: Option<Daily>,
+    /// Indices of the chosen sets in `ModifierAssets::mutators`.
+    pub mutators: Vec<usize>,
+    /// Meta upgrades a replayed run was recorded with, instead of the ones of the profile.
+    pub bonuses: Option<MetaBonuses>,
+}
+
+impl RunSetup {
+    /// Picks the seed of the next run, unless level.ron or the daily forces one.
+    pub fn roll_seed(&mut self, level_assets: &LevelAssets) {
+        self.seed = match &self.daily {
+            Some(daily) => daily.seed,
+            None => level_assets.seed.unwrap_or_else(random_seed),
+        };
+    }
+
+    pub fn modifier_sets<'a>(&'a self, assets: &'a ModifierAssets) -> Vec<&'a ModifierSet> {
+        match &self.daily {
+            Some(daily) => vec![&daily.rules],
+            None => self
+                .mutators
+                .iter()
+                .filter_map(|index| assets.mutators.get(*index))
+                .collect(),
+        }
+    }
+
+    pub fn bonuses(&self, shop_assets: &ShopAssets, profile: &Profile) -> MetaBonuses {
+        self.bonuses.unwrap_or_else(|| shop_assets.bonuses(profile))
+    }
+
+    pub fn toggle_mutator(&mut self, index: usize) {
+        match self.mutators.iter().position(|i| *i == index) {
+            Some(position) => {
+                self.mutators.remove(position);
+            }
+            None => self.mutators.push(index),
+        }
+    }
+}
+
+pub fn random_seed() -> u32 {
+    ThreadRng::default().next_u32()
+}
+
+/// Seeds are shared as text: numbers are kept as is, any other text is hashed.
+pub fn seed_from_text(text: &str) -> Option<u32> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    // FNV-1a, stable across platforms and versions.
+    let seed = text.parse().unwrap_or_else(|_| {
+        text.bytes().fold(0x811c9dc5_u32, |hash, byte| {
+            (hash ^ byte as u32).wrapping_mul(0x01000193)
+        })
+    });
+    Some(seed)
+}
+
+/// Time left before the player can call the extraction.
+#[derive(Resource, Debug)]
+pub struct ExtractionCountdown(pub Timer);
+
+fn start_extraction_countdown(
+    mut commands: Commands,
+    level_assets: Res<LevelAssets>,
+    run_setup: Res<RunSetup>,
+) {
+    // Without any duration in level.ron, the extraction can be called right away.
+    let minutes = level_assets
+        .extraction(run_setup.extraction)
+        .map_or(0, |duration| duration.minutes);
+    commands.insert_resource(ExtractionCountdown(Timer::from_seconds(
+        minutes as f32 * 60.,
+        TimerMode::Once,
+    )));
+}
+
+fn tick_extraction_countdown(time: Res<Time>, mut countdown: ResMut<ExtractionCountdown>) {
+    countdown.0.tick(time.delta());
+}
+
+/// Inserts the `RunSummary` when the run ends, screens showing it run after.
+#[derive(SystemSet, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Summary;
+
+/// Counters of the current run not tracked anywhere else.
+#[derive(Resource, Debug, Default)]
+pub struct RunStats {
+    /// Damage dealt by each weapon, in the order they were first used.
+    pub damage_dealt: Vec<(String, f32)>,
+    pub damage_taken: f32,
+    pub xp_collected: f32,
+}
+
+impl RunStats {
+    pub fn deal_damage(&mut self, weapon: &str, amount: f32) {
+        match self
+            .damage_dealt
+            .iter_mut()
+            .find(|(name, _)| name == weapon)
+        {
+            Some((_, total)) => *total += amount,
+            None => self.damage_dealt.push((weapon.to_string(), amount)),
+        }
+    }
+}
+
+fn reset_run_stats(mut commands: Commands) {
+    commands.insert_resource(RunStats::default());
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RunEnd {
+    Death,
+    Extraction,
+}
+
+/// Everything shown at the end of a run, also appended to the run history.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone)]
+pub struct RunSummary {
+    pub end: RunEnd,
+    pub seed: u32,
+    /// Missing in summaries saved before it was recorded.
+    #[serde(default)]
+    pub character: String,
+    /// In seconds.
+    pub time_survived: f32,
+    pub kills: u32,
+    pub level: u32,
+    pub damage_dealt: Vec<(String, f32)>,
+    pub damage_taken: f32,
+    pub xp_collected: f32,
+    /// Brought back, after any unpaid clone cost.
+    pub credits: u32,
+    /// Paid from the profile to send the clone.
+    pub clone_cost: u32,
+    /// Names of the modifier sets of the run.
+    #[serde(default)]
+    pub modifiers: Vec<String>,
+}
+
+impl RunSummary {
+    /// Credits brought back minus the clone cost.
+    pub fn net(&self) -> i64 {
+        self.credits as i64 - self.clone_cost as i64
+    }
+}
+
+fn summarize_run(
+    end: RunEnd,
+) -> impl Fn(
+    Commands,
+    Res<RunStats>,
+    Res<SurvivalTimer>,
+    Res<EliminationCount>,
+    Res<Credits>,
+    Res<PlayerAssets>,
+    Res<ModifierAssets>,
+    Res<RunSetup>,
+    Single<&Xp, With<Player>>,
+) {
+    move |mut commands,
+          stats,
+          survival,
+          eliminations,
+          credits,
+          player_assets,
+          modifier_assets,
+          run_setup,
+          xp| {
+        commands.insert_resource(RunSummary {
+            end,
+            seed: run_setup.seed,
+            character: player_assets.character(run_setup.character).name.clone(),
+            time_survived: survival.0.elapsed_secs(),
+            kills: eliminations.0,
+            level: xp.level,
+            damage_dealt: stats.damage_dealt.clone(),
+            damage_taken: stats.damage_taken,
+            xp_collected: stats.xp_collected,
+            credits: credits.settled.unwrap_or_default(),
+            clone_cost: credits.paid(),
+            modifiers: run_setup
+                .modifier_sets(&modifier_assets)
+                .iter()
+                .map(|set| set.name.clone())
+                .collect(),
+        });
+    }
+}
@@ -39,6 +39,9 @@ pub struct TilesetAssets {
 
 #[derive(Deserialize, Clone, Debug)]
 pub struct GroundTilesetWithVariant {
+    /// Colour of the ground on the minimap, in sRGB.
+    #[serde(default)]
+    pub minimap_color: Option<Vec3>,
     pub tiles: Tileset,
     pub variants: HashMap<GroundVariant, Tileset>,
 }
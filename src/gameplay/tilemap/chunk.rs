@@ -15,7 +15,7 @@ pub fn chunk_tile_data(
     tileset_assets: &TilesetAssets,
 ) -> TilemapChunkTileData {
     let chunk_size = tileset_assets.chunk_size as i32;
-    let ctx = TilemapGenerator::new(seed + 33, planet_size.x as f32, planet_size.y as f32);
+    let ctx = ground_generator(seed, planet_size);
 
     let tile_data: Vec<Option<TileData>> = (0..chunk_size)
         .cartesian_product(0..chunk_size)
@@ -31,6 +31,10 @@ pub fn chunk_tile_data(
     TilemapChunkTileData(tile_data)
 }
 
+pub fn ground_generator(seed: u32, planet_size: UVec2) -> TilemapGenerator {
+    TilemapGenerator::new(seed + 33, planet_size.x as f32, planet_size.y as f32)
+}
+
 pub fn tilemap_chunk(tileset_assets: &TilesetAssets) -> TilemapChunk {
     let chunk_size = UVec2::splat(tileset_assets.chunk_size);
     let tile_display_size = UVec2::splat(tileset_assets.tile_size);
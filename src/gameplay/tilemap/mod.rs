@@ -1,9 +1,13 @@
 use crate::gameplay::layer::GameLayer;
 use crate::gameplay::level::{LevelAssets, TilemapOrigin};
 use crate::gameplay::tilemap::asset::{TilesetAssets, convert_tileset_to_array};
-use crate::gameplay::tilemap::chunk::{chunk_pixel_size, chunk_tile_data, tilemap_chunk};
+use crate::gameplay::tilemap::chunk::{
+    chunk_pixel_size, chunk_tile_data, ground_generator, tilemap_chunk,
+};
+use bevy::asset::RenderAssetUsages;
 use bevy::ecs::relationship::RelatedSpawnerCommands;
 use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
 use itertools::Itertools;
 
 pub mod asset;
@@ -18,8 +22,66 @@ pub fn plugin(app: &mut App) {
 #[derive(Component, Reflect)]
 pub struct ChunkPlanetPos(pub IVec2);
 
+/// Size of the whole planet, in pixels.
+pub fn planet_pixel_size(level_assets: &LevelAssets, tileset_assets: &TilesetAssets) -> Vec2 {
+    UVec2::new(level_assets.planet_width, level_assets.planet_height).as_vec2()
+        * tileset_assets.tile_size as f32
+}
+
+/// Position on the planet, in pixels, of a world position.
+pub fn planet_position(world: Vec2, planet_size: Vec2) -> Vec2 {
+    world.rem_euclid(planet_size)
+}
+
+/// World position of a planet position, taking the shortest way around the planet from `near`.
+pub fn world_position(planet: Vec2, near: Vec2, planet_size: Vec2) -> Vec2 {
+    let delta = (planet - planet_position(near, planet_size) + planet_size / 2.)
+        .rem_euclid(planet_size)
+        - planet_size / 2.;
+    near + delta
+}
+
+/// Low resolution picture of the whole planet ground, `None` when a ground has no minimap colour.
+pub fn ground_preview(
+    seed: u32,
+    level_assets: &LevelAssets,
+    tileset_assets: &TilesetAssets,
+    resolution: u32,
+) -> Option<Image> {
+    let planet_size = UVec2::new(level_assets.planet_width, level_assets.planet_height);
+    let ctx = ground_generator(seed, planet_size);
+    let mut data = Vec::with_capacity((resolution * resolution * 4) as usize);
+    for row in 0..resolution {
+        for column in 0..resolution {
+            // Image rows go down while planet rows go up.
+            let pos = UVec2::new(column, resolution - 1 - row) * planet_size / resolution;
+            let color = tileset_assets
+                .grounds
+                .get(&ctx.ground_type(pos.as_ivec2()))?
+                .minimap_color?;
+            data.extend_from_slice(
+                &Color::srgb_from_array(color.into())
+                    .to_srgba()
+                    .to_u8_array(),
+            );
+        }
+    }
+    Some(Image::new(
+        Extent3d {
+            width: resolution,
+            height: resolution,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    ))
+}
+
 pub fn spawn_tilemap(
     commands: &mut RelatedSpawnerCommands<ChildOf>,
+    seed: u32,
     level_assets: &LevelAssets,
     tileset_assets: &TilesetAssets,
     images: &mut Assets<Image>,
@@ -38,8 +100,7 @@ pub fn spawn_tilemap(
                 (ox * cs).rem_euclid(planet_size.x as i32),
                 (oy * cs).rem_euclid(planet_size.y as i32),
             );
-            let tile_data =
-                chunk_tile_data(level_assets.seed, planet_size, planet_pos, tileset_assets);
+            let tile_data = chunk_tile_data(seed, planet_size, planet_pos, tileset_assets);
 
             (
                 Transform::from_xyz(ox as f32 * chunk_px, oy as f32 * chunk_px, 0.),
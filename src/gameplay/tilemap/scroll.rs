@@ -1,22 +1,26 @@
-use crate::gameplay::level::{LevelAssets, TilemapOrigin, WorldEntity};
+use crate::gameplay::level::{LevelAssets, PlanetAnchor, TilemapOrigin, WorldEntity};
 use crate::gameplay::player::Player;
+use crate::gameplay::run::RunSetup;
 use crate::gameplay::tilemap::chunk::{chunk_pixel_size, chunk_tile_data};
-use crate::gameplay::tilemap::{ChunkPlanetPos, TilesetAssets};
+use crate::gameplay::tilemap::{ChunkPlanetPos, TilesetAssets, planet_pixel_size, world_position};
+use crate::{AppSystems, PausableSystems};
 use bevy::prelude::*;
 use bevy::sprite_render::TilemapChunkTileData;
 
 const CAMERA_DECAY_RATE: f32 = 2.;
 
 pub fn plugin(app: &mut App) {
+    // Shifting the world changes positions, so it happens on the tick the player crosses over.
+    app.add_systems(
+        FixedUpdate,
+        (recenter_world, wrap_planet_anchors)
+            .chain()
+            .after(AppSystems::Update)
+            .in_set(PausableSystems),
+    );
     app.add_systems(
         Update,
-        (
-            update_tilemap_origin,
-            recycle_chunks,
-            update_camera,
-            recenter_world,
-        )
-            .chain(),
+        (update_tilemap_origin, recycle_chunks, update_camera).chain(),
     );
 }
 
@@ -47,6 +51,7 @@ fn recycle_chunks(
     >,
     tileset_assets: Res<TilesetAssets>,
     level_assets: Res<LevelAssets>,
+    run_setup: Res<RunSetup>,
 ) {
     let chunk_px = chunk_pixel_size(&tileset_assets);
     let cs = tileset_assets.chunk_size as i32;
@@ -97,7 +102,7 @@ fn recycle_chunks(
             && let Some(new_pos) = uncovered_iter.next()
         {
             planet_pos.0 = new_pos;
-            *tile_data = chunk_tile_data(level_assets.seed, planet_size, new_pos, &tileset_assets);
+            *tile_data = chunk_tile_data(run_setup.seed, planet_size, new_pos, &tileset_assets);
         }
 
         let chunk_x = planet_pos.0.x / cs;
@@ -173,6 +178,24 @@ fn recenter_world(
     }
 }
 
+fn wrap_planet_anchors(
+    player: Single<&Transform, With<Player>>,
+    mut anchors: Query<(&mut Transform, &PlanetAnchor), Without<Player>>,
+    tileset_assets: Option<Res<TilesetAssets>>,
+    level_assets: Option<Res<LevelAssets>>,
+) {
+    let (Some(tileset_assets), Some(level_assets)) = (tileset_assets, level_assets) else {
+        return;
+    };
+    let planet_size = planet_pixel_size(&level_assets, &tileset_assets);
+    let player = player.translation.truncate();
+    for (mut transform, anchor) in &mut anchors {
+        let position = world_position(anchor.0, player, planet_size);
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+    }
+}
+
 fn update_camera(
     mut camera: Single<&mut Transform, (With<Camera2d>, Without<Player>)>,
     player: Single<&Transform, (With<Player>, Without<Camera2d>)>,
@@ -0,0 +1,76 @@
+use crate::gameplay::health::Health;
+use crate::gameplay::player::weapon::{Weapon, WeaponAssets, WeaponSlots};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Improvement granted by a level up or a chest.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq, Reflect)]
+pub enum Upgrade {
+    Damage,
+    BulletSpeed,
+    FireRate,
+    MaxHealth,
+    /// Fills a free slot with the weapon at this index in weapon.ron.
+    Weapon(usize),
+}
+
+impl Upgrade {
+    pub const ALL: [Upgrade; 4] = [
+        Upgrade::Damage,
+        Upgrade::BulletSpeed,
+        Upgrade::FireRate,
+        Upgrade::MaxHealth,
+    ];
+
+    /// The stat upgrades, and the next weapon while a slot is free.
+    pub fn offered(slots: &WeaponSlots, weapon_assets: &WeaponAssets) -> Vec<Upgrade> {
+        let mut upgrades = Self::ALL.to_vec();
+        upgrades.extend(slots.next_weapon(weapon_assets).map(Upgrade::Weapon));
+        upgrades
+    }
+
+    pub fn label(&self, weapon_assets: &WeaponAssets) -> String {
+        match self {
+            Upgrade::Damage => "Damage ++".to_string(),
+            Upgrade::BulletSpeed => "Bullet speed ++".to_string(),
+            Upgrade::FireRate => "Fire rate ++".to_string(),
+            Upgrade::MaxHealth => "Health max ++".to_string(),
+            Upgrade::Weapon(index) => weapon_assets
+                .types
+                .get(*index)
+                .map_or("New weapon".to_string(), |weapon| {
+                    format!("New {}", weapon.name)
+                }),
+        }
+    }
+
+    /// Weapon upgrades only improve the first slot, a new weapon is lost once the slots are full.
+    pub fn apply(
+        &self,
+        slots: &mut WeaponSlots,
+        health: &mut Health,
+        weapon_assets: &WeaponAssets,
+    ) {
+        match self {
+            Upgrade::MaxHealth => health.inc_max_health(0.10),
+            Upgrade::Weapon(index) => {
+                if let Some(weapon) = weapon_assets.types.get(*index)
+                    && slots.slots.len() < slots.capacity
+                {
+                    slots.slots.push(Weapon::new(weapon.clone()));
+                }
+            }
+            _ => {
+                let Some(w) = slots.slots.get_mut(0) else {
+                    return;
+                };
+                match self {
+                    Upgrade::Damage => w.inc_damage(0.10),
+                    Upgrade::BulletSpeed => w.inc_speed(0.10),
+                    Upgrade::FireRate => w.inc_fire_rate(0.50),
+                    Upgrade::MaxHealth | Upgrade::Weapon(_) => {}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::asset_tracking::LoadResource;
+use bevy::prelude::*;
+use ron_asset_manager::Shandle;
+use ron_asset_manager::prelude::RonAsset;
+use serde::Deserialize;
+
+pub fn plugin(app: &mut App) {
+    app.load_resource::<WorldEventAssets>("events.ron");
+}
+
+#[derive(Resource, Asset, RonAsset, TypePath, Deserialize, Clone, Debug)]
+pub struct WorldEventAssets {
+    #[asset]
+    pub events: Vec<WorldEventType>,
+}
+
+#[derive(RonAsset, Deserialize, Clone, Debug)]
+pub struct WorldEventType {
+    pub name: String,
+    pub announcement: String,
+    pub trigger: EventTrigger,
+    /// Distance, in pixels, between the player and the event when it starts.
+    pub distance: f32,
+    #[asset]
+    pub landmark: Shandle<Image>,
+    pub landmark_size: Vec2,
+    pub guards: Vec<EventGuards>,
+    /// Pickups dropped around the landmark when the event starts.
+    pub loot: Vec<EventReward>,
+    /// Granted to the player once every guard is killed.
+    pub completion: Vec<EventReward>,
+}
+
+#[derive(Deserialize, Copy, Clone, Debug)]
+pub struct EventTrigger {
+    /// The event may start each time the player level is a multiple of this value.
+    pub every_levels: u32,
+    pub chance: f64,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct EventGuards {
+    /// Name of the enemy type from enemy.ron.
+    pub enemy: String,
+    pub count: u32,
+    /// Radius of the ring the guards are spawned on, around the landmark.
+    pub radius: f32,
+}
+
+#[derive(Deserialize, Copy, Clone, Debug)]
+pub enum EventReward {
+    Xp(f32),
+    Heal(f32),
+}
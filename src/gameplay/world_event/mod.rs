@@ -0,0 +1,192 @@
+use crate::gameplay::animation::Animation;
+use crate::gameplay::enemy::asset::{Enemy, EnemyAssets};
+use crate::gameplay::enemy::{EnemyRoot, enemy};
+use crate::gameplay::health::Health;
+use crate::gameplay::layer::GameLayer;
+use crate::gameplay::level::{LevelAssets, PlanetAnchor, RandomSeed};
+use crate::gameplay::loot::asset::LootAssets;
+use crate::gameplay::loot::{first_aid, xp_gem};
+use crate::gameplay::player::{LevelUp, Player, Xp};
+use crate::gameplay::tilemap::asset::TilesetAssets;
+use crate::gameplay::tilemap::{planet_pixel_size, planet_position};
+use crate::hud::indicator::OffscreenIndicator;
+use crate::hud::minimap::MinimapMarker;
+use crate::hud::{Announcement, spawn_heal_popup};
+use crate::screen::Screen;
+use crate::{AppSystems, PausableSystems};
+use asset::{EventReward, WorldEventAssets, WorldEventType};
+use bevy::prelude::*;
+use rand::RngExt;
+use std::f32::consts::PI;
+
+pub mod asset;
+
+/// Distance between the landmark and the loot dropped around it.
+const LOOT_RADIUS: f32 = 40.0;
+
+pub fn plugin(app: &mut App) {
+    app.add_plugins(asset::plugin);
+    app.add_systems(
+        FixedUpdate,
+        (start_events, complete_events)
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems)
+            .run_if(in_state(Screen::Gameplay(false))),
+    );
+}
+
+/// An event in progress, as long as some of its guards are alive.
+#[derive(Component, Reflect)]
+pub struct WorldEvent {
+    /// Index in `WorldEventAssets::events`.
+    pub index: usize,
+}
+
+/// Enemy guarding a world event.
+#[derive(Component, Reflect)]
+pub struct EventGuard(pub Entity);
+
+fn start_events(
+    mut commands: Commands,
+    xp: Single<&Xp, (With<Player>, Changed<Xp>)>,
+    mut last_level: Local<u32>,
+    player: Single<&Transform, With<Player>>,
+    events: Query<&WorldEvent>,
+    event_assets: Res<WorldEventAssets>,
+    enemy_assets: Res<EnemyAssets>,
+    loot_assets: Res<LootAssets>,
+    level_assets: Res<LevelAssets>,
+    tileset_assets: Res<TilesetAssets>,
+    enemy_root: Single<Entity, With<EnemyRoot>>,
+    mut rng: ResMut<RandomSeed>,
+    mut animations: ResMut<Assets<Animation>>,
+    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    // Also catches a new run starting back at level 1.
+    if xp.level <= *last_level {
+        *last_level = xp.level;
+        return;
+    }
+    *last_level = xp.level;
+
+    let planet_size = planet_pixel_size(&level_assets, &tileset_assets);
+    for (index, event_type) in event_assets.events.iter().enumerate() {
+        let trigger = event_type.trigger;
+        if !xp.level.is_multiple_of(trigger.every_levels)
+            || events.iter().any(|event| event.index == index)
+            || !rng.events.random_bool(trigger.chance)
+        {
+            continue;
+        }
+
+        let angle = rng.events.random_range(0.0..2.0 * PI);
+        let position =
+            player.translation.truncate() + Vec2::from_angle(angle) * event_type.distance;
+        let event = commands
+            .spawn(landmark(
+                index,
+                event_type,
+                planet_position(position, planet_size),
+            ))
+            .with_children(|parent| {
+                for (i, reward) in event_type.loot.iter().enumerate() {
+                    let angle = i as f32 * 2. * PI / event_type.loot.len() as f32;
+                    let offset = Vec2::from_angle(angle) * LOOT_RADIUS;
+                    match *reward {
+                        EventReward::Xp(amount) => {
+                            parent.spawn(xp_gem(&loot_assets.xp, amount, offset));
+                        }
+                        EventReward::Heal(amount) => {
+                            parent.spawn(first_aid(&loot_assets.heal.sprite, amount, offset));
+                        }
+                    }
+                }
+            })
+            .id();
+
+        commands.entity(*enemy_root).with_children(|parent| {
+            for guards in &event_type.guards {
+                let Some(enemy_type) = enemy_assets.types.iter().find(|e| e.name == guards.enemy)
+                else {
+                    warn!(
+                        "Unknown enemy {} in event {}",
+                        guards.enemy, event_type.name
+                    );
+                    continue;
+                };
+                for i in 0..guards.count {
+                    let angle = i as f32 * 2. * PI / guards.count as f32;
+                    parent.spawn((
+                        enemy(
+                            position + Vec2::from_angle(angle) * guards.radius,
+                            enemy_type,
+                            &mut animations,
+                            &mut texture_atlas_layouts,
+                        ),
+                        EventGuard(event),
+                    ));
+                }
+            }
+        });
+
+        commands.trigger(Announcement(event_type.announcement.clone()));
+    }
+}
+
+fn landmark(index: usize, event_type: &WorldEventType, planet: Vec2) -> impl Bundle {
+    (
+        Name::new(event_type.name.clone()),
+        WorldEvent { index },
+        PlanetAnchor(planet),
+        MinimapMarker::Landmark,
+        OffscreenIndicator::Objective,
+        GameLayer::Zone,
+        DespawnOnExit(Screen::Gameplay(false)),
+        Sprite {
+            image: event_type.landmark.handle.clone(),
+            custom_size: Some(event_type.landmark_size),
+            ..default()
+        },
+        Transform::default(),
+        Visibility::default(),
+    )
+}
+
+fn complete_events(
+    mut commands: Commands,
+    events: Query<(Entity, &WorldEvent)>,
+    guards: Query<&EventGuard, With<Enemy>>,
+    event_assets: Res<WorldEventAssets>,
+    player: Single<(&mut Xp, &mut Health, &Transform), With<Player>>,
+    mut rng: ResMut<RandomSeed>,
+) {
+    let (mut xp, mut health, transform) = player.into_inner();
+    for (entity, event) in &events {
+        if guards.iter().any(|guard| guard.0 == entity) {
+            continue;
+        }
+        let event_type = &event_assets.events[event.index];
+        for reward in &event_type.completion {
+            match *reward {
+                EventReward::Xp(amount) => {
+                    if xp.add(amount) {
+                        commands.trigger(LevelUp);
+                    }
+                }
+                EventReward::Heal(amount) => {
+                    let healed = health.heal(amount);
+                    spawn_heal_popup(
+                        &mut commands,
+                        &mut rng.effects,
+                        transform.translation,
+                        healed,
+                    );
+                }
+            }
+        }
+        commands
+            .entity(entity)
+            .remove::<(WorldEvent, OffscreenIndicator)>();
+        commands.trigger(Announcement(format!("{} cleared", event_type.name)));
+    }
+}
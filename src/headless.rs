@@ -0,0 +1,223 @@
+//! Test harness: the game without window, rendering nor audio, stepped one fixed timestep per
+//! update and driven by scripted keyboard input.
+
+use crate::GamePlugin;
+use crate::asset_tracking::ResourceHandles;
+use crate::gameplay::enemy::asset::Enemy;
+use crate::gameplay::health::Health;
+use crate::gameplay::hit_stop::HitStop;
+use crate::gameplay::player::{Player, Xp};
+use crate::gameplay::replay::MenuChoice;
+use crate::gameplay::run::RunSetup;
+use crate::hud::elimination::EliminationCount;
+use crate::menu::Menu;
+use crate::screen::Screen;
+use bevy::audio::AudioLoader;
+use bevy::image::{CompressedImageFormats, ImageLoader};
+use bevy::input::InputPlugin;
+use bevy::input_focus::InputFocusPlugin;
+use bevy::mesh::MeshPlugin;
+use bevy::prelude::*;
+use bevy::sprite_render::{TilemapChunkMaterial, TilemapChunkPlugin};
+use bevy::state::app::StatesPlugin;
+use bevy::text::FontLoader;
+use bevy::time::TimeUpdateStrategy;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Fixed ticks in a second of game time, each update runs one.
+pub const TICKS_PER_SECOND: usize = 64;
+const LOAD_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Starts a run with `seed` once every asset is loaded, the level is spawned by the first update.
+pub fn headless_app(seed: u32) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        StatesPlugin,
+        AssetPlugin::default(),
+        TransformPlugin,
+        InputPlugin,
+        InputFocusPlugin,
+        MeshPlugin,
+        TilemapChunkPlugin,
+    ))
+    .init_asset::<Image>()
+    .init_asset::<TextureAtlasLayout>()
+    .init_asset::<ColorMaterial>()
+    .init_asset::<TilemapChunkMaterial>()
+    .init_asset::<AudioSource>()
+    .init_asset::<Font>()
+    // The loaders the rendering, audio and text plugins would register.
+    .register_asset_loader(ImageLoader::new(CompressedImageFormats::NONE))
+    .init_asset_loader::<AudioLoader>()
+    .init_asset_loader::<FontLoader>()
+    .insert_resource(TimeUpdateStrategy::ManualDuration(
+        Time::<Fixed>::default().timestep(),
+    ))
+    .add_plugins(GamePlugin);
+
+    app.world_mut().resource_mut::<HitStop>().enabled = false;
+    app.world_mut().spawn((Name::new("Camera"), Camera2d));
+    app.finish();
+    app.cleanup();
+    load_assets(&mut app);
+
+    app.world_mut().resource_mut::<RunSetup>().seed = seed;
+    app.world_mut()
+        .resource_mut::<NextState<Screen>>()
+        .set(Screen::Gameplay(false));
+    app
+}
+
+/// Updates until the RON files of the assets folder are loaded with everything they point to.
+fn load_assets(app: &mut App) {
+    let folder = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets");
+    let files: Vec<_> = std::fs::read_dir(folder)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|file| file.ends_with(".ron"))
+        .collect();
+    let loaded = |app: &App| {
+        let asset_server = app.world().resource::<AssetServer>();
+        app.world().resource::<ResourceHandles>().is_all_done()
+            && files.iter().all(|file| {
+                asset_server
+                    .get_path_id(file.clone())
+                    .is_some_and(|id| asset_server.is_loaded_with_dependencies(id))
+            })
+    };
+    let start = Instant::now();
+    while !loaded(app) {
+        assert!(
+            start.elapsed() < LOAD_TIMEOUT,
+            "Assets not loaded after {LOAD_TIMEOUT:?}"
+        );
+        app.update();
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+/// Runs `ticks` updates holding `keys`. Level-ups are skipped, so the player keeps its starting
+/// stats.
+pub fn hold(app: &mut App, keys: &[KeyCode], ticks: usize) {
+    play(app, keys, ticks, MenuChoice::SkipUpgrade);
+}
+
+/// Runs `ticks` updates holding `keys`, answering level-ups with `level_up` and collecting chests.
+pub fn play(app: &mut App, keys: &[KeyCode], ticks: usize, level_up: MenuChoice) {
+    let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    for key in keys {
+        input.press(*key);
+    }
+    for _ in 0..ticks {
+        let answered = !matches!(
+            *app.world().resource::<NextState<Menu>>(),
+            NextState::Unchanged
+        );
+        match menu(app) {
+            _ if answered => {}
+            Menu::LevelUp => app.world_mut().trigger(level_up),
+            Menu::Chest => app.world_mut().trigger(MenuChoice::CollectChest),
+            _ => {}
+        }
+        app.update();
+    }
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release_all();
+}
+
+pub fn wait(app: &mut App, ticks: usize) {
+    hold(app, &[], ticks);
+}
+
+pub fn menu(app: &App) -> Menu {
+    *app.world().resource::<State<Menu>>().get()
+}
+
+/// What two runs given the same seed and inputs must agree on.
+#[derive(Debug, PartialEq)]
+pub struct WorldState {
+    pub player: Vec3,
+    pub health: f32,
+    pub level: u32,
+    pub xp: f32,
+    pub kills: u32,
+    pub enemies: Vec<(Vec3, f32)>,
+}
+
+impl WorldState {
+    pub fn read(app: &mut App) -> Self {
+        let world = app.world_mut();
+        let (transform, health, xp) = world
+            .query_filtered::<(&Transform, &Health, &Xp), With<Player>>()
+            .single(world)
+            .unwrap();
+        let (player, health, level, xp) =
+            (transform.translation, health.current, xp.level, xp.current);
+        let enemies = world
+            .query_filtered::<(&Transform, &Health), With<Enemy>>()
+            .iter(world)
+            .map(|(transform, health)| (transform.translation, health.current))
+            .collect();
+        Self {
+            player,
+            health,
+            level,
+            xp,
+            kills: world.resource::<EliminationCount>().0,
+            enemies,
+        }
+    }
+}
+
+mod tests {
+    use super::*;
+
+    /// Walks right for 5 seconds, then stands still for 15.
+    fn simulate(seed: u32) -> WorldState {
+        let mut app = headless_app(seed);
+        hold(&mut app, &[KeyCode::KeyD], 5 * TICKS_PER_SECOND);
+        wait(&mut app, 15 * TICKS_PER_SECOND);
+        WorldState::read(&mut app)
+    }
+
+    #[test]
+    fn same_seed_same_world() {
+        let first = simulate(42);
+        assert!(!first.enemies.is_empty());
+        assert_eq!(first, simulate(42));
+        assert_ne!(first, simulate(7));
+    }
+
+    #[test]
+    fn standing_still_fights_then_dies() {
+        let mut app = headless_app(42);
+        wait(&mut app, 1);
+        let start = WorldState::read(&mut app);
+
+        wait(&mut app, 12 * TICKS_PER_SECOND);
+        let fighting = WorldState::read(&mut app);
+        assert!(fighting.kills > 0);
+        assert!(fighting.xp > 0.);
+        assert!(fighting.health < start.health);
+
+        wait(&mut app, 18 * TICKS_PER_SECOND);
+        assert_eq!(WorldState::read(&mut app).health, 0.);
+        assert_eq!(menu(&app), Menu::GameOver);
+    }
+
+    #[test]
+    fn walking_away_avoids_damage() {
+        let mut app = headless_app(42);
+        wait(&mut app, 1);
+        let start = WorldState::read(&mut app);
+
+        hold(&mut app, &[KeyCode::KeyD], 30 * TICKS_PER_SECOND);
+        let state = WorldState::read(&mut app);
+        assert_eq!(state.health, start.health);
+        assert!(state.player.x > start.player.x);
+        assert_eq!(menu(&app), Menu::None);
+    }
+}
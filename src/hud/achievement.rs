@@ -0,0 +1,76 @@
+use crate::gameplay::achievement::AchievementUnlocked;
+use crate::screen::Screen;
+use bevy::prelude::*;
+
+const BACKGROUND_COLOR: Color = Color::srgba(0.05, 0.05, 0.12, 0.85);
+const TITLE_COLOR: Color = Color::srgb(1.0, 0.85, 0.3);
+const DESCRIPTION_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const DURATION: f32 = 4.0;
+
+pub fn plugin(app: &mut App) {
+    app.add_observer(spawn_popup);
+    app.add_systems(Update, despawn_popups);
+}
+
+#[derive(Component)]
+struct PopupTimer(Timer);
+
+fn spawn_popup(
+    on: On<AchievementUnlocked>,
+    mut commands: Commands,
+    previous: Query<Entity, With<PopupTimer>>,
+) {
+    for entity in &previous {
+        commands.entity(entity).despawn();
+    }
+    commands.spawn((
+        Name::new("Achievement Popup"),
+        PopupTimer(Timer::from_seconds(DURATION, TimerMode::Once)),
+        DespawnOnExit(Screen::Gameplay(false)),
+        GlobalZIndex(10),
+        Pickable::IGNORE,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(16.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        children![(
+            Node {
+                padding: UiRect::all(Val::Px(12.0)),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            BackgroundColor(BACKGROUND_COLOR),
+            children![
+                (
+                    Name::new("Achievement Title"),
+                    Text(format!("Achievement unlocked: {}", on.name)),
+                    TextFont::from_font_size(20.0),
+                    TextColor(TITLE_COLOR),
+                ),
+                (
+                    Name::new("Achievement Description"),
+                    Text(on.description.clone()),
+                    TextFont::from_font_size(14.0),
+                    TextColor(DESCRIPTION_COLOR),
+                )
+            ],
+        )],
+    ));
+}
+
+fn despawn_popups(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut popups: Query<(Entity, &mut PopupTimer)>,
+) {
+    for (entity, mut timer) in &mut popups {
+        if timer.0.tick(time.delta()).just_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::screen::Screen;
+use bevy::prelude::*;
+
+const ANNOUNCEMENT_COLOR: Color = Color::srgb(1.0, 0.85, 0.3);
+const DURATION: f32 = 3.0;
+
+pub fn plugin(app: &mut App) {
+    app.add_observer(spawn_announcement);
+    app.add_systems(Update, fade_announcements);
+}
+
+/// Message shown at the top of the screen for a few seconds.
+#[derive(Event)]
+pub struct Announcement(pub String);
+
+#[derive(Component)]
+struct AnnouncementTimer(Timer);
+
+fn spawn_announcement(
+    on: On<Announcement>,
+    mut commands: Commands,
+    previous: Query<Entity, With<AnnouncementTimer>>,
+) {
+    for entity in &previous {
+        commands.entity(entity).despawn();
+    }
+    commands.spawn((
+        Name::new("Announcement"),
+        AnnouncementTimer(Timer::from_seconds(DURATION, TimerMode::Once)),
+        DespawnOnExit(Screen::Gameplay(false)),
+        GlobalZIndex(10),
+        Pickable::IGNORE,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(20.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        children![(
+            Name::new("Announcement Text"),
+            Text(on.0.clone()),
+            TextFont::from_font_size(32.0),
+            TextColor(ANNOUNCEMENT_COLOR),
+        )],
+    ));
+}
+
+fn fade_announcements(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut announcements: Query<(Entity, &mut AnnouncementTimer, &Children)>,
+    mut colors: Query<&mut TextColor>,
+) {
+    for (entity, mut timer, children) in &mut announcements {
+        timer.0.tick(time.delta());
+        let alpha = (timer.0.remaining_secs() / (DURATION / 3.)).min(1.);
+        let mut colors = colors.iter_many_mut(children);
+        while let Some(mut color) = colors.fetch_next() {
+            color.0.set_alpha(alpha);
+        }
+        if timer.0.just_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
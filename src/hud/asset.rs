@@ -0,0 +1,21 @@
+use crate::asset_tracking::LoadResource;
+use bevy::prelude::*;
+use ron_asset_manager::Shandle;
+use ron_asset_manager::prelude::RonAsset;
+use serde::Deserialize;
+
+pub fn plugin(app: &mut App) {
+    app.load_resource::<HudAssets>("hud.ron");
+}
+
+#[derive(Resource, Asset, RonAsset, TypePath, Deserialize, Clone, Debug)]
+pub struct HudAssets {
+    /// Points at off-screen entities.
+    #[asset]
+    pub arrow: Shandle<Image>,
+    /// Covers the locked weapon slots.
+    #[asset]
+    pub lock: Shandle<Image>,
+    #[asset]
+    pub skull: Shandle<Image>,
+}
@@ -1,6 +1,8 @@
 use crate::gameplay::GameLayer;
 use bevy::prelude::*;
 use bevy::time::Timer;
+use rand::RngExt;
+use rand::rngs::StdRng;
 
 pub fn plugin(app: &mut App) {
     app.add_systems(Update, update_damage_popups);
@@ -12,17 +14,40 @@ struct DamagePopup {
     velocity: Vec2,
 }
 
-pub fn spawn_damage_popup(commands: &mut Commands, position: Vec3, damage: f32) {
-    let offset_x = rand::random::<f32>() * 20.0 - 5.0;
+const DAMAGE_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
+const HEAL_COLOR: Color = Color::srgb(0.2, 0.9, 0.3);
+
+pub fn spawn_damage_popup(commands: &mut Commands, rng: &mut StdRng, position: Vec3, damage: f32) {
+    spawn_popup(commands, rng, position, damage.to_string(), DAMAGE_COLOR);
+}
+
+pub fn spawn_heal_popup(commands: &mut Commands, rng: &mut StdRng, position: Vec3, amount: f32) {
+    spawn_popup(
+        commands,
+        rng,
+        position,
+        format!("+{}", amount.round()),
+        HEAL_COLOR,
+    );
+}
+
+fn spawn_popup(
+    commands: &mut Commands,
+    rng: &mut StdRng,
+    position: Vec3,
+    text: String,
+    color: Color,
+) {
+    let offset_x = rng.random::<f32>() * 20.0 - 5.0;
 
     commands.spawn((
         GameLayer::AimZone,
-        Text2d::new(damage.to_string()),
+        Text2d::new(text),
         TextFont {
             font_size: FontSize::Px(20.0),
             ..default()
         },
-        TextColor(Color::srgb(1.0, 1.0, 1.0)),
+        TextColor(color),
         Transform::from_translation(position + Vec3::new(offset_x, 20.0, 1.0)),
         DamagePopup {
             timer: Timer::from_seconds(1.0, TimerMode::Once),
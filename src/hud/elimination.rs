@@ -1,4 +1,6 @@
 use crate::PausableSystems;
+use crate::gameplay::credits::Credits;
+use crate::hud::asset::HudAssets;
 use crate::hud::panel::{PanelPosition, panel};
 use crate::screen::Screen;
 use bevy::prelude::*;
@@ -6,27 +8,39 @@ use bevy::prelude::*;
 const LABEL_COLOR: Color = Color::srgba(0.5, 0.5, 0.6, 0.8);
 const COUNT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
 const ICON_SIZE: f32 = 20.0;
+const BREAK_EVEN_COLOR: Color = Color::srgb(0.6, 0.6, 0.7);
+const PAID_OFF_COLOR: Color = Color::srgb(1.0, 0.85, 0.3);
 
 pub fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::Gameplay(false)), spawn_elimination_panel);
-    app.add_systems(Update, update_elimination.in_set(PausableSystems));
+    app.add_systems(
+        Update,
+        (update_elimination, update_credits, update_break_even).in_set(PausableSystems),
+    );
 }
 
 #[derive(Component)]
 struct EliminationText;
 
+#[derive(Component)]
+struct CreditsText;
+
+/// Fill of the bar showing how much of the clone cost was earned back.
+#[derive(Component)]
+struct BreakEvenFill;
+
 #[derive(Resource, Default)]
 pub struct EliminationCount(pub u32);
 
-fn spawn_elimination_panel(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn spawn_elimination_panel(mut commands: Commands, hud_assets: Res<HudAssets>) {
     commands.insert_resource(EliminationCount::default());
 
-    let skull = asset_server.load("images/hud/skull.png");
+    let skull = hud_assets.skull.handle.clone();
     commands.spawn((
         panel("Elimination", PanelPosition::BottomRight),
         DespawnOnExit(Screen::Gameplay(false)),
         GlobalZIndex(10),
-        children![elimination_row(skull)],
+        children![elimination_row(skull), credits_row(), break_even_bar()],
     ));
 }
 
@@ -42,6 +56,54 @@ fn elimination_row(skull: Handle<Image>) -> impl Bundle {
     )
 }
 
+fn credits_row() -> impl Bundle {
+    (
+        Name::new("Credits Row"),
+        Node {
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(10.0),
+            ..default()
+        },
+        children![
+            (
+                Name::new("Credits Label"),
+                Text("CREDITS".into()),
+                TextFont::from_font_size(12.0),
+                TextColor(LABEL_COLOR),
+            ),
+            (
+                CreditsText,
+                Name::new("Credits Count"),
+                Text("0".into()),
+                TextFont::from_font_size(28.0),
+                TextColor(COUNT_COLOR),
+            )
+        ],
+    )
+}
+
+fn break_even_bar() -> impl Bundle {
+    (
+        Name::new("Break Even Bar"),
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Px(4.0),
+            ..default()
+        },
+        BackgroundColor(LABEL_COLOR),
+        children![(
+            BreakEvenFill,
+            Name::new("Break Even Fill"),
+            Node {
+                width: Val::Percent(0.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            BackgroundColor(BREAK_EVEN_COLOR),
+        )],
+    )
+}
+
 fn elim_label() -> impl Bundle {
     (
         Name::new("Elim Label"),
@@ -83,3 +145,20 @@ fn update_elimination(
 ) {
     text.0 = format!("{}", count.0);
 }
+
+fn update_credits(credits: Res<Credits>, mut text: Single<&mut Text, With<CreditsText>>) {
+    text.0 = format!("{}", credits.earned as u32);
+}
+
+fn update_break_even(
+    credits: Res<Credits>,
+    fill: Single<(&mut Node, &mut BackgroundColor), With<BreakEvenFill>>,
+) {
+    let (mut node, mut color) = fill.into_inner();
+    node.width = Val::Percent(credits.break_even() * 100.0);
+    color.0 = if credits.paid_off() {
+        PAID_OFF_COLOR
+    } else {
+        BREAK_EVEN_COLOR
+    };
+}
@@ -0,0 +1,112 @@
+use crate::hud::asset::HudAssets;
+use crate::screen::Screen;
+use crate::utils::camera_world_rect;
+use bevy::prelude::*;
+
+const ARROW_MAX_SIZE: f32 = 28.0;
+const ARROW_MIN_SIZE: f32 = 12.0;
+/// Distance from the screen edge, in world pixels.
+const EDGE_MARGIN: f32 = 30.0;
+/// Distance beyond the screen at which arrows reach their minimum size.
+const FAR_DISTANCE: f32 = 3000.0;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (spawn_arrows, update_arrows)
+            .chain()
+            .run_if(in_state(Screen::Gameplay(false))),
+    );
+}
+
+/// Points an arrow at the entity from the screen edge while it is off-screen.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+pub enum OffscreenIndicator {
+    Threat,
+    Landmark,
+    Objective,
+}
+
+impl OffscreenIndicator {
+    fn color(&self) -> Color {
+        match self {
+            OffscreenIndicator::Threat => Color::srgb(1.0, 0.2, 0.15),
+            OffscreenIndicator::Landmark => Color::srgb(0.3, 0.6, 1.0),
+            OffscreenIndicator::Objective => Color::srgb(0.2, 1.0, 0.4),
+        }
+    }
+}
+
+#[derive(Component)]
+struct IndicatorArrow(Entity);
+
+fn spawn_arrows(
+    mut commands: Commands,
+    hud_assets: Res<HudAssets>,
+    indicators: Query<(Entity, &OffscreenIndicator), Added<OffscreenIndicator>>,
+) {
+    for (entity, indicator) in &indicators {
+        commands.spawn((
+            Name::new("Indicator Arrow"),
+            IndicatorArrow(entity),
+            DespawnOnExit(Screen::Gameplay(false)),
+            GlobalZIndex(9),
+            ImageNode {
+                image: hud_assets.arrow.handle.clone(),
+                color: indicator.color(),
+                ..default()
+            },
+            Node {
+                position_type: PositionType::Absolute,
+                display: Display::None,
+                ..default()
+            },
+            UiTransform::default(),
+        ));
+    }
+}
+
+fn update_arrows(
+    mut commands: Commands,
+    mut arrows: Query<(Entity, &IndicatorArrow, &mut Node, &mut UiTransform)>,
+    targets: Query<&GlobalTransform, With<OffscreenIndicator>>,
+    camera: Single<(&Camera, &GlobalTransform)>,
+    window: Single<&Window>,
+) {
+    let (camera, camera_transform) = *camera;
+    let Some(view) = camera_world_rect(camera, camera_transform, &window) else {
+        return;
+    };
+    let center = view.center();
+    let inner = view.inflate(-EDGE_MARGIN);
+
+    for (arrow, IndicatorArrow(target), mut node, mut transform) in &mut arrows {
+        let Ok(target) = targets.get(*target) else {
+            commands.entity(arrow).despawn();
+            continue;
+        };
+        let position = target.translation().truncate();
+        if view.contains(position) {
+            node.display = Display::None;
+            continue;
+        }
+
+        // Where the line from the screen center to the target leaves the inner rect.
+        let delta = position - center;
+        let scale = (inner.half_size() / delta.abs()).min_element();
+        let edge = center + delta * scale;
+        let Ok(screen) = camera.world_to_viewport(camera_transform, edge.extend(0.)) else {
+            continue;
+        };
+
+        let beyond = (delta.length() - delta.length() * scale).max(0.);
+        let size = ARROW_MAX_SIZE.lerp(ARROW_MIN_SIZE, (beyond / FAR_DISTANCE).min(1.));
+        node.display = Display::Flex;
+        node.width = Val::Px(size);
+        node.height = Val::Px(size);
+        node.left = Val::Px(screen.x - size / 2.);
+        node.top = Val::Px(screen.y - size / 2.);
+        // UI rotation is clockwise, world angles are counterclockwise.
+        transform.rotation = Rot2::radians(-delta.to_angle());
+    }
+}
@@ -0,0 +1,140 @@
+use crate::gameplay::level::LevelAssets;
+use crate::gameplay::run::RunSetup;
+use crate::gameplay::tilemap::asset::TilesetAssets;
+use crate::gameplay::tilemap::{ground_preview, planet_pixel_size, planet_position};
+use crate::hud::panel::{PanelPosition, panel};
+use crate::screen::Screen;
+use bevy::prelude::*;
+
+const MAP_SIZE: f32 = 160.0;
+const PREVIEW_RESOLUTION: u32 = 100;
+const MAP_BG_COLOR: Color = Color::srgba(0.2, 0.2, 0.25, 0.8);
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay(false)), spawn_minimap_panel);
+    app.add_systems(
+        Update,
+        (spawn_marker_dots, update_marker_dots)
+            .chain()
+            .run_if(in_state(Screen::Gameplay(false))),
+    );
+}
+
+/// Shows the entity on the minimap.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+pub enum MinimapMarker {
+    Player,
+    Landmark,
+    Boss,
+    Extraction,
+}
+
+impl MinimapMarker {
+    fn color(&self) -> Color {
+        match self {
+            MinimapMarker::Player => Color::WHITE,
+            MinimapMarker::Landmark => Color::srgb(0.3, 0.6, 1.0),
+            MinimapMarker::Boss => Color::srgb(1.0, 0.15, 0.1),
+            MinimapMarker::Extraction => Color::srgb(0.2, 1.0, 0.4),
+        }
+    }
+
+    fn size(&self) -> f32 {
+        match self {
+            MinimapMarker::Player => 6.0,
+            MinimapMarker::Landmark | MinimapMarker::Extraction => 8.0,
+            MinimapMarker::Boss => 10.0,
+        }
+    }
+}
+
+#[derive(Component)]
+struct MinimapArea;
+
+/// UI dot following a `MinimapMarker` entity.
+#[derive(Component)]
+struct MarkerDot(Entity);
+
+fn spawn_minimap_panel(
+    mut commands: Commands,
+    level_assets: Res<LevelAssets>,
+    tileset_assets: Res<TilesetAssets>,
+    run_setup: Res<RunSetup>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let area = commands
+        .spawn((
+            Name::new("Minimap Area"),
+            MinimapArea,
+            Node {
+                width: Val::Px(MAP_SIZE),
+                height: Val::Px(MAP_SIZE),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            BackgroundColor(MAP_BG_COLOR),
+        ))
+        .id();
+    if let Some(preview) = ground_preview(
+        run_setup.seed,
+        &level_assets,
+        &tileset_assets,
+        PREVIEW_RESOLUTION,
+    ) {
+        commands
+            .entity(area)
+            .insert(ImageNode::new(images.add(preview)));
+    }
+
+    commands
+        .spawn((
+            panel("Minimap", PanelPosition::MiddleRight),
+            DespawnOnExit(Screen::Gameplay(false)),
+            GlobalZIndex(10),
+        ))
+        .add_child(area);
+}
+
+fn spawn_marker_dots(
+    mut commands: Commands,
+    area: Single<Entity, With<MinimapArea>>,
+    markers: Query<(Entity, &MinimapMarker), Added<MinimapMarker>>,
+) {
+    for (entity, marker) in &markers {
+        let size = marker.size();
+        commands.entity(*area).with_child((
+            Name::new("Marker Dot"),
+            MarkerDot(entity),
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Px(size),
+                height: Val::Px(size),
+                border_radius: BorderRadius::MAX,
+                ..default()
+            },
+            BackgroundColor(marker.color()),
+            // The player stays above every other dot.
+            ZIndex(matches!(marker, MinimapMarker::Player) as i32),
+        ));
+    }
+}
+
+fn update_marker_dots(
+    mut commands: Commands,
+    mut dots: Query<(Entity, &MarkerDot, &mut Node)>,
+    markers: Query<(&GlobalTransform, &MinimapMarker)>,
+    level_assets: Res<LevelAssets>,
+    tileset_assets: Res<TilesetAssets>,
+) {
+    let planet_size = planet_pixel_size(&level_assets, &tileset_assets);
+    for (dot, MarkerDot(target), mut node) in &mut dots {
+        let Ok((transform, marker)) = markers.get(*target) else {
+            commands.entity(dot).despawn();
+            continue;
+        };
+        let uv = planet_position(transform.translation().truncate(), planet_size) / planet_size;
+        let half = marker.size() / 2.;
+        node.left = Val::Px(uv.x * MAP_SIZE - half);
+        node.top = Val::Px((1. - uv.y) * MAP_SIZE - half);
+    }
+}
@@ -1,24 +1,37 @@
 use bevy::prelude::*;
 
+mod achievement;
+mod announcement;
+pub mod asset;
 mod damage_popup;
 pub mod elimination;
+pub mod indicator;
+pub mod minimap;
 pub mod panel;
 mod player;
 mod progress_bar;
 pub mod timer;
+mod vignette;
 mod weapon;
 
-pub use damage_popup::spawn_damage_popup;
+pub use announcement::Announcement;
+pub use damage_popup::{spawn_damage_popup, spawn_heal_popup};
 pub use progress_bar::ProgressBar;
 pub use progress_bar::progress_bar;
 
 pub fn plugin(app: &mut App) {
     app.add_plugins((
+        asset::plugin,
         progress_bar::plugin,
         damage_popup::plugin,
         player::plugin,
         weapon::plugin,
         timer::plugin,
         elimination::plugin,
+        vignette::plugin,
+        minimap::plugin,
+        indicator::plugin,
+        announcement::plugin,
+        achievement::plugin,
     ));
 }
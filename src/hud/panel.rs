@@ -6,6 +6,7 @@ pub fn panel(name: impl Into<String>, position: PanelPosition) -> impl Bundle {
     let (top, right, bottom, left) = match position {
         PanelPosition::TopLeft => (Val::Px(16.0), Val::Auto, Val::Auto, Val::Px(16.0)),
         PanelPosition::TopRight => (Val::Px(16.0), Val::Px(16.0), Val::Auto, Val::Auto),
+        PanelPosition::MiddleRight => (Val::Percent(35.0), Val::Px(16.0), Val::Auto, Val::Auto),
         PanelPosition::BottomLeft => (Val::Auto, Val::Auto, Val::Px(16.0), Val::Px(16.0)),
         PanelPosition::BottomRight => (Val::Auto, Val::Px(16.0), Val::Px(16.0), Val::Auto),
     };
@@ -31,6 +32,7 @@ pub fn panel(name: impl Into<String>, position: PanelPosition) -> impl Bundle {
 pub enum PanelPosition {
     TopLeft,
     TopRight,
+    MiddleRight,
     BottomLeft,
     BottomRight,
 }
@@ -1,11 +1,12 @@
 use crate::gameplay::health::Health;
-use crate::gameplay::player::{Player, Xp};
+use crate::gameplay::player::{Dash, Player, Xp};
 use crate::hud::panel::{PanelPosition, panel};
 use crate::screen::Screen;
 use bevy::prelude::*;
 
 const HP_BAR_COLOR: Color = Color::srgb(0.85, 0.1, 0.1);
 const XP_BAR_COLOR: Color = Color::srgb(0.7, 0.1, 0.85);
+const DASH_BAR_COLOR: Color = Color::srgb(0.1, 0.75, 0.85);
 const BAR_BG_COLOR: Color = Color::srgba(0.2, 0.2, 0.25, 0.8);
 
 const BORDER_WIDTH: Val = Val::Px(1.);
@@ -15,7 +16,7 @@ const BAR_RADIUS: Val = Val::Px(2.0);
 
 pub fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::Gameplay(false)), spawn_player_panel);
-    app.add_systems(Update, (update_hp_bar, update_xp_bar));
+    app.add_systems(Update, (update_hp_bar, update_xp_bar, update_dash_bar));
 }
 
 #[derive(Component)]
@@ -30,12 +31,15 @@ struct XpBarFill;
 #[derive(Component)]
 struct LevelText;
 
+#[derive(Component)]
+struct DashBarFill;
+
 fn spawn_player_panel(mut commands: Commands) {
     commands.spawn((
         panel("Player Status", PanelPosition::TopLeft),
         DespawnOnExit(Screen::Gameplay(false)),
         GlobalZIndex(10),
-        children![hp_row(), xp_row()],
+        children![hp_row(), xp_row(), dash_row()],
     ));
 }
 
@@ -151,6 +155,52 @@ fn level_text() -> impl Bundle {
     )
 }
 
+fn dash_row() -> impl Bundle {
+    (
+        Name::new("Dash Row"),
+        Node {
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            ..default()
+        },
+        children![dash_label(), dash_bar()],
+    )
+}
+
+fn dash_label() -> impl Bundle {
+    (
+        Name::new("Dash Label"),
+        Text("DASH".into()),
+        TextFont::from_font_size(14.0),
+        TextColor(DASH_BAR_COLOR),
+    )
+}
+
+fn dash_bar() -> impl Bundle {
+    (
+        Name::new("Dash Bar"),
+        Node {
+            width: Val::Px(BAR_WIDTH),
+            height: Val::Px(BAR_HEIGHT / 2.0),
+            border: UiRect::all(BORDER_WIDTH),
+            border_radius: BorderRadius::all(BAR_RADIUS),
+            ..default()
+        },
+        BackgroundColor(BAR_BG_COLOR),
+        BorderColor::from(DASH_BAR_COLOR),
+        children![(
+            DashBarFill,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                border_radius: BorderRadius::all(BAR_RADIUS),
+                ..default()
+            },
+            BackgroundColor(DASH_BAR_COLOR),
+        )],
+    )
+}
+
 fn update_hp_bar(
     mut bar: Single<&mut Node, With<HpBarFill>>,
     mut text: Single<&mut Text, With<HpText>>,
@@ -178,3 +228,10 @@ fn update_xp_bar(
     bar.width = Val::Percent(ratio * 100.0);
     text.0 = format!("Lv. {:02}", xp.level);
 }
+
+fn update_dash_bar(
+    mut bar: Single<&mut Node, With<DashBarFill>>,
+    dash: Single<&Dash, With<Player>>,
+) {
+    bar.width = Val::Percent(dash.cooldown.fraction() * 100.0);
+}
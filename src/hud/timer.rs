@@ -1,6 +1,8 @@
-use crate::PausableSystems;
+use crate::gameplay::extraction::Extraction;
+use crate::gameplay::run::ExtractionCountdown;
 use crate::hud::panel::{PanelPosition, panel};
 use crate::screen::Screen;
+use crate::{AppSystems, PausableSystems};
 use bevy::prelude::*;
 use bevy::time::Stopwatch;
 
@@ -9,6 +11,12 @@ const TIME_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
 
 pub fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::Gameplay(false)), spawn_timer_panel);
+    app.add_systems(
+        FixedUpdate,
+        tick_survival_timer
+            .in_set(AppSystems::TickTimers)
+            .in_set(PausableSystems),
+    );
     app.add_systems(Update, update_timer.in_set(PausableSystems));
 }
 
@@ -44,7 +52,7 @@ fn timer_row() -> impl Bundle {
 fn timer_label() -> impl Bundle {
     (
         Name::new("Timer Label"),
-        Text("TEMPS SURVECU".into()),
+        Text("EXFILTRATION".into()),
         TextFont::from_font_size(12.0),
         TextColor(LABEL_COLOR),
     )
@@ -60,13 +68,24 @@ fn timer_value() -> impl Bundle {
     )
 }
 
+fn tick_survival_timer(time: Res<Time>, mut survival: ResMut<SurvivalTimer>) {
+    survival.0.tick(time.delta());
+}
+
 fn update_timer(
-    time: Res<Time>,
-    mut survival: ResMut<SurvivalTimer>,
+    countdown: Res<ExtractionCountdown>,
+    extraction: Res<Extraction>,
     mut text: Single<&mut Text, With<TimerText>>,
 ) {
-    survival.0.tick(time.delta());
-    let secs = survival.0.elapsed_secs() as u32;
+    let remaining = match &*extraction {
+        Extraction::Waiting => countdown.0.remaining_secs(),
+        Extraction::Ready => {
+            text.0 = "[E]".into();
+            return;
+        }
+        Extraction::Landing { countdown } => countdown.remaining_secs(),
+    };
+    let secs = remaining.ceil() as u32;
     let minutes = secs / 60;
     let seconds = secs % 60;
     text.0 = format!("{:02}:{:02}", minutes, seconds);
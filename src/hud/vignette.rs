@@ -0,0 +1,68 @@
+use crate::PausableSystems;
+use crate::gameplay::player::PlayerHurt;
+use crate::screen::Screen;
+use bevy::prelude::*;
+
+const VIGNETTE_COLOR: Color = Color::srgb(0.85, 0.05, 0.05);
+const VIGNETTE_DURATION: f32 = 0.5;
+const VIGNETTE_MAX_ALPHA: f32 = 0.6;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay(false)), spawn_vignette);
+    app.add_systems(Update, update_vignette.in_set(PausableSystems));
+    app.add_observer(show_vignette);
+}
+
+#[derive(Component)]
+struct HurtVignette {
+    timer: Timer,
+}
+
+fn spawn_vignette(mut commands: Commands) {
+    let mut timer = Timer::from_seconds(VIGNETTE_DURATION, TimerMode::Once);
+    timer.finish();
+
+    commands.spawn((
+        Name::new("Hurt Vignette"),
+        HurtVignette { timer },
+        Node {
+            position_type: PositionType::Absolute,
+            width: percent(100),
+            height: percent(100),
+            ..default()
+        },
+        vignette_gradient(0.0),
+        Pickable::IGNORE,
+        DespawnOnExit(Screen::Gameplay(false)),
+        GlobalZIndex(5),
+    ));
+}
+
+fn vignette_gradient(alpha: f32) -> BackgroundGradient {
+    RadialGradient::new(
+        UiPosition::CENTER,
+        RadialGradientShape::FarthestCorner,
+        vec![
+            ColorStop::new(VIGNETTE_COLOR.with_alpha(0.0), percent(55)),
+            ColorStop::new(VIGNETTE_COLOR.with_alpha(alpha), percent(100)),
+        ],
+    )
+    .into()
+}
+
+fn show_vignette(_on: On<PlayerHurt>, mut vignette: Single<&mut HurtVignette>) {
+    vignette.timer.reset();
+}
+
+fn update_vignette(
+    time: Res<Time>,
+    vignette: Single<(&mut HurtVignette, &mut BackgroundGradient)>,
+) {
+    let (mut vignette, mut gradient) = vignette.into_inner();
+    if vignette.timer.is_finished() {
+        return;
+    }
+    vignette.timer.tick(time.delta());
+    let alpha = vignette.timer.fraction_remaining() * VIGNETTE_MAX_ALPHA;
+    *gradient = vignette_gradient(alpha);
+}
@@ -1,3 +1,5 @@
+use crate::gameplay::player::weapon::WeaponSlots;
+use crate::hud::asset::HudAssets;
 use crate::hud::panel::{PanelPosition, panel};
 use crate::screen::Screen;
 use bevy::prelude::*;
@@ -13,10 +15,14 @@ const SLOT_COUNT: usize = 5;
 
 pub fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::Gameplay(false)), spawn_weapon_panel);
+    app.add_systems(Update, update_slot_locks);
 }
 
-fn spawn_weapon_panel(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let lock = asset_server.load("images/hud/lock.png");
+#[derive(Component)]
+struct SlotLock(usize);
+
+fn spawn_weapon_panel(mut commands: Commands, hud_assets: Res<HudAssets>) {
+    let lock = hud_assets.lock.handle.clone();
 
     commands.spawn((
         panel("Weapon", PanelPosition::TopRight),
@@ -52,13 +58,14 @@ fn weapon_slot(index: usize, lock: Handle<Image>) -> impl Bundle {
         },
         BackgroundColor(SLOT_BG),
         BorderColor::from(SLOT_BORDER_COLOR),
-        children![lock_icon(lock), slot_index(index)],
+        children![lock_icon(index, lock), slot_index(index)],
     )
 }
 
-fn lock_icon(lock: Handle<Image>) -> impl Bundle {
+fn lock_icon(index: usize, lock: Handle<Image>) -> impl Bundle {
     (
         Name::new("Lock"),
+        SlotLock(index),
         ImageNode {
             image: lock,
             color: LOCK_COLOR,
@@ -86,3 +93,16 @@ fn slot_index(index: usize) -> impl Bundle {
         },
     )
 }
+
+fn update_slot_locks(
+    slots: Single<&WeaponSlots, Changed<WeaponSlots>>,
+    mut locks: Query<(&SlotLock, &mut Visibility)>,
+) {
+    for (lock, mut visibility) in &mut locks {
+        *visibility = if lock.0 < slots.capacity {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+    }
+}
@@ -8,12 +8,15 @@ mod audio;
 mod gameplay;
 mod hud;
 mod menu;
+mod profile;
 mod screen;
 mod theme;
 mod utils;
 
 #[cfg(feature = "dev")]
 mod dev_tools;
+#[cfg(test)]
+mod headless;
 
 use crate::menu::Menu;
 use crate::screen::Screen;
@@ -22,6 +25,7 @@ use avian2d::PhysicsPlugins;
 use avian2d::prelude::Gravity;
 use bevy::camera::Hdr;
 use bevy::core_pipeline::tonemapping::Tonemapping;
+use bevy::ecs::schedule::{ScheduleLabel, SingleThreadedExecutor};
 use bevy::input::common_conditions::input_just_pressed;
 use bevy::post_process::bloom::Bloom;
 use bevy::window::{PresentMode, PrimaryWindow, WindowMode, WindowResolution};
@@ -57,40 +61,58 @@ impl Plugin for AppPlugin {
                     ..default()
                 })
                 .set(ImagePlugin::default_nearest()),
+            GamePlugin,
+            #[cfg(feature = "dev")]
+            dev_tools::plugin,
+        ));
+
+        app.add_systems(Startup, spawn_camera);
+        app.add_systems(
+            Update,
+            change_window_mode.run_if(input_just_pressed(KeyCode::F3)),
+        );
+    }
+}
+
+/// The game without its window, rendering and audio output, so tests can run it headless.
+pub struct GamePlugin;
+
+impl Plugin for GamePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
             asset_tracking::plugin,
             audio::plugin,
             gameplay::plugin,
             PhysicsPlugins::default(),
             menu::plugin,
+            profile::plugin,
             screen::plugin,
             theme::plugin,
             hud::plugin,
-            #[cfg(feature = "dev")]
-            dev_tools::plugin,
         ))
         .insert_resource(Gravity::ZERO);
 
-        app.configure_sets(
-            Update,
-            (
-                AppSystems::TickTimers,
-                AppSystems::RecordInput,
-                AppSystems::Update,
-            )
-                .chain(),
-        );
-
-        app.configure_sets(
-            Update,
-            PausableSystems.run_if(in_state(MetaState::InGame).and_then(in_state(Menu::None))),
-        );
+        // Input is recorded every frame, the simulation runs on the fixed timestep.
+        for schedule in [Update.intern(), FixedUpdate.intern()] {
+            app.configure_sets(
+                schedule,
+                (
+                    AppSystems::TickTimers,
+                    AppSystems::RecordInput,
+                    AppSystems::Update,
+                )
+                    .chain(),
+            );
+            app.configure_sets(
+                schedule,
+                PausableSystems.run_if(in_state(MetaState::InGame).and_then(menu_closed)),
+            );
+        }
+        // Ambiguous system orders would make runs diverge, so the simulation runs on one thread.
+        app.edit_schedule(FixedUpdate, |schedule| {
+            schedule.set_executor(SingleThreadedExecutor::new());
+        });
         app.add_computed_state::<MetaState>();
-
-        app.add_systems(Startup, spawn_camera);
-        app.add_systems(
-            Update,
-            change_window_mode.run_if(input_just_pressed(KeyCode::F3)),
-        );
     }
 }
 
@@ -104,6 +126,16 @@ enum AppSystems {
 #[derive(SystemSet, Copy, Clone, Eq, PartialEq, Hash, Debug)]
 struct PausableSystems;
 
+/// Also false once a menu is about to open, so a menu opened by the simulation stops it from the
+/// next fixed tick on, however many ticks the frame runs.
+fn menu_closed(menu: Res<State<Menu>>, next: Res<NextState<Menu>>) -> bool {
+    let opening = matches!(
+        *next,
+        NextState::Pending(menu) | NextState::PendingIfNeq(menu) if menu != Menu::None
+    );
+    *menu.get() == Menu::None && !opening
+}
+
 fn spawn_camera(mut commands: Commands) {
     commands.spawn((
         Name::new("Camera"),
@@ -0,0 +1,89 @@
+use crate::gameplay::player::asset::{CharacterType, PlayerAssets};
+use crate::gameplay::run::RunSetup;
+use crate::menu::{Menu, MenuAssets, Nav};
+use crate::theme::widget::*;
+use crate::utils::escape_just_pressed;
+use bevy::{ecs::spawn::SpawnIter, prelude::*};
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::CharacterSelect), spawn_character_select_menu);
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::CharacterSelect).and_then(escape_just_pressed)),
+    );
+}
+
+fn spawn_character_select_menu(
+    mut commands: Commands,
+    assets: Res<MenuAssets>,
+    player_assets: Res<PlayerAssets>,
+) {
+    let cards: Vec<_> = player_assets
+        .characters
+        .iter()
+        .enumerate()
+        .map(|(index, character)| character_card(&assets, index, character))
+        .collect();
+
+    commands.spawn((
+        ui_root("Character Select Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::CharacterSelect),
+        children![
+            header(&assets, "Choose your character"),
+            (
+                Name::new("Characters"),
+                Node {
+                    column_gap: px(40),
+                    ..default()
+                },
+                Children::spawn(SpawnIter(cards.into_iter())),
+            ),
+            button(&assets, "Back", go_back_on_click),
+        ],
+    ));
+}
+
+fn character_card(assets: &MenuAssets, index: usize, character: &CharacterType) -> impl Bundle {
+    let font = assets.font.handle.clone();
+    let size = assets.font_size_base - 6.;
+    (
+        Name::new("Character Card"),
+        Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: px(6),
+            ..default()
+        },
+        children![
+            button(
+                assets,
+                character.name.clone(),
+                move |_: On<Pointer<Click>>,
+                      mut run_setup: ResMut<RunSetup>,
+                      mut commands: Commands| {
+                    run_setup.character = index;
+                    commands.trigger(Nav::Open(Menu::ExtractionTime));
+                }
+            ),
+            label(
+                font.clone(),
+                size,
+                format!(
+                    "HP {} - Speed {}",
+                    character.max_health, character.max_speed
+                )
+            ),
+            label(font.clone(), size, character.weapon.clone()),
+            label(font, size, character.passive.description()),
+        ],
+    )
+}
+
+fn go_back_on_click(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.trigger(Nav::Back);
+}
+
+fn go_back(mut commands: Commands) {
+    commands.trigger(Nav::Back);
+}
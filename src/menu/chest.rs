@@ -0,0 +1,169 @@
+use crate::gameplay::health::Health;
+use crate::gameplay::level::RandomSeed;
+use crate::gameplay::loot::asset::LootAssets;
+use crate::gameplay::loot::{ChestContents, RerollTokens, roll_upgrades};
+use crate::gameplay::player::Player;
+use crate::gameplay::player::weapon::{WeaponAssets, WeaponSlots};
+use crate::gameplay::replay::MenuChoice;
+use crate::gameplay::upgrade::Upgrade;
+use crate::menu::{Menu, MenuAssets};
+use crate::theme::widget;
+use bevy::{ecs::spawn::SpawnIter, prelude::*};
+use widget::{button, header, label, ui_root};
+
+/// Delay between two revealed upgrades, in seconds.
+const REVEAL_DELAY: f32 = 0.4;
+const REVEAL_DURATION: f32 = 0.3;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::Chest), spawn_chest_menu);
+    app.add_systems(Update, reveal_upgrades.run_if(in_state(Menu::Chest)));
+    app.add_observer(collect);
+    app.add_observer(reroll);
+}
+
+/// Grows the node from nothing once the delay has elapsed.
+#[derive(Component)]
+struct Reveal(Timer);
+
+#[derive(Component)]
+struct ChestMenuRoot;
+
+fn spawn_chest_menu(
+    mut commands: Commands,
+    assets: Res<MenuAssets>,
+    weapon_assets: Res<WeaponAssets>,
+    contents: Res<ChestContents>,
+    tokens: Res<RerollTokens>,
+) {
+    commands.spawn(chest_menu(&assets, &weapon_assets, &contents, tokens.0));
+}
+
+fn chest_menu(
+    assets: &MenuAssets,
+    weapon_assets: &WeaponAssets,
+    contents: &ChestContents,
+    rerolls: u32,
+) -> impl Bundle {
+    let font = assets.font.handle.clone();
+    let size = assets.font_size_base;
+    let upgrades: Vec<_> = contents
+        .0
+        .iter()
+        .enumerate()
+        .map(|(i, upgrade)| {
+            (
+                label(font.clone(), size, upgrade.label(weapon_assets)),
+                Reveal(Timer::from_seconds(
+                    REVEAL_DELAY * i as f32 + REVEAL_DURATION,
+                    TimerMode::Once,
+                )),
+                UiTransform::from_scale(Vec2::ZERO),
+            )
+        })
+        .collect();
+
+    (
+        ui_root("Chest Menu"),
+        ChestMenuRoot,
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::Chest),
+        children![
+            header(assets, "Chest"),
+            (
+                Name::new("Upgrades"),
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: px(10),
+                    ..default()
+                },
+                Children::spawn(SpawnIter(upgrades.into_iter())),
+            ),
+            (
+                Name::new("Actions"),
+                Node {
+                    column_gap: px(20),
+                    ..default()
+                },
+                children![
+                    button(assets, "Collect", collect_on_click),
+                    reroll_button(assets, rerolls),
+                ],
+            ),
+        ],
+    )
+}
+
+fn reroll_button(assets: &MenuAssets, rerolls: u32) -> impl Bundle {
+    let text = format!("Reroll ({rerolls})");
+    (
+        button(assets, text, reroll_on_click),
+        if rerolls == 0 {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        },
+    )
+}
+
+fn reveal_upgrades(time: Res<Time<Real>>, mut reveals: Query<(&mut Reveal, &mut UiTransform)>) {
+    for (mut reveal, mut transform) in &mut reveals {
+        reveal.0.tick(time.delta());
+        let progress = 1. - (reveal.0.remaining_secs() / REVEAL_DURATION).min(1.);
+        transform.scale = Vec2::splat(progress);
+    }
+}
+
+fn collect_on_click(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.trigger(MenuChoice::CollectChest);
+}
+
+fn reroll_on_click(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.trigger(MenuChoice::RerollChest);
+}
+
+fn collect(
+    choice: On<MenuChoice>,
+    contents: Res<ChestContents>,
+    weapon_assets: Res<WeaponAssets>,
+    mut health: Single<&mut Health, With<Player>>,
+    mut slots: Single<&mut WeaponSlots>,
+    mut next: ResMut<NextState<Menu>>,
+) {
+    if *choice != MenuChoice::CollectChest {
+        return;
+    }
+    for upgrade in &contents.0 {
+        upgrade.apply(&mut slots, &mut health, &weapon_assets);
+    }
+    next.set(Menu::None);
+}
+
+fn reroll(
+    choice: On<MenuChoice>,
+    mut commands: Commands,
+    root: Single<Entity, With<ChestMenuRoot>>,
+    assets: Res<MenuAssets>,
+    loot_assets: Res<LootAssets>,
+    weapon_assets: Res<WeaponAssets>,
+    slots: Single<&WeaponSlots>,
+    mut contents: ResMut<ChestContents>,
+    mut tokens: ResMut<RerollTokens>,
+    mut rng: ResMut<RandomSeed>,
+) {
+    if *choice != MenuChoice::RerollChest {
+        return;
+    }
+    let Some(rerolls) = tokens.0.checked_sub(1) else {
+        return;
+    };
+    tokens.0 = rerolls;
+    contents.0 = roll_upgrades(
+        &mut rng,
+        loot_assets.chest.max_upgrades,
+        &Upgrade::offered(&slots, &weapon_assets),
+    );
+    commands.entity(*root).despawn();
+    commands.spawn(chest_menu(&assets, &weapon_assets, &contents, rerolls));
+}
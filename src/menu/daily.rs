@@ -0,0 +1,117 @@
+use crate::gameplay::daily::{self, Daily, DailyAssets, DailyResult};
+use crate::gameplay::run::RunSetup;
+use crate::menu::run_summary::{format_time, stat_grid};
+use crate::menu::{Menu, MenuAssets, Nav};
+use crate::profile::Profile;
+use crate::theme::widget::*;
+use crate::utils::escape_just_pressed;
+use bevy::{ecs::spawn::SpawnIter, prelude::*};
+
+/// Number of previous dailies listed.
+const LAST_RESULTS: usize = 7;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::Daily), spawn_daily_menu);
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::Daily).and_then(escape_just_pressed)),
+    );
+}
+
+fn spawn_daily_menu(
+    mut commands: Commands,
+    assets: Res<MenuAssets>,
+    daily_assets: Res<DailyAssets>,
+    profile: Res<Profile>,
+) {
+    let font = assets.font.handle.clone();
+    let size = assets.font_size_base;
+    let Some(daily) = daily_assets.today() else {
+        commands.spawn((
+            ui_root("Daily Menu"),
+            GlobalZIndex(2),
+            DespawnOnExit(Menu::Daily),
+            children![
+                header(&assets, "Daily challenge"),
+                label(font, size, "The date is unknown"),
+                button(&assets, "Back", go_back_on_click),
+            ],
+        ));
+        return;
+    };
+
+    let results = daily::results();
+    let attempted = daily.attempted(&profile, &results);
+    let status = match results.iter().find(|result| result.date == daily.date) {
+        Some(result) => format!("Scored: {}", score(result)),
+        None if attempted => "Your attempt was abandoned".to_string(),
+        None => "Your first attempt is scored".to_string(),
+    };
+    let play = if attempted { "Practice" } else { "Play" };
+    let modifiers: Vec<_> = daily
+        .rules
+        .modifiers
+        .iter()
+        .map(|modifier| label(font.clone(), size - 6., modifier.description()))
+        .collect();
+    let last_results = results
+        .iter()
+        .rev()
+        .take(LAST_RESULTS)
+        .map(|result| (result.date.clone(), score(result)))
+        .collect();
+
+    commands.spawn((
+        ui_root("Daily Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::Daily),
+        children![
+            header(&assets, "Daily challenge"),
+            label(
+                font.clone(),
+                size,
+                format!("{} - {}", daily.date, daily.rules.name)
+            ),
+            (
+                Name::new("Modifiers"),
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: px(4),
+                    ..default()
+                },
+                Children::spawn(SpawnIter(modifiers.into_iter())),
+            ),
+            label(font.clone(), size, status),
+            button(&assets, play, play_daily(daily)),
+            label(font, size, "Previous dailies"),
+            stat_grid(&assets, last_results),
+            button(&assets, "Back", go_back_on_click),
+        ],
+    ));
+}
+
+fn score(result: &DailyResult) -> String {
+    format!(
+        "{} - {} kills - {} credits",
+        format_time(result.summary.time_survived),
+        result.summary.kills,
+        result.summary.credits
+    )
+}
+
+fn play_daily(daily: Daily) -> impl Fn(On<Pointer<Click>>, ResMut<RunSetup>, Commands) {
+    move |_, mut run_setup, mut commands| {
+        run_setup.seed = daily.seed;
+        run_setup.daily = Some(daily.clone());
+        commands.trigger(Nav::Open(Menu::CharacterSelect));
+    }
+}
+
+fn go_back_on_click(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.trigger(Nav::Back);
+}
+
+fn go_back(mut commands: Commands) {
+    commands.trigger(Nav::Back);
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+
+use crate::gameplay::level::LevelAssets;
+use crate::gameplay::run::{RunSetup, RunSummary, Summary};
+use crate::menu::MenuAssets;
+use crate::menu::run_summary::run_summary;
+use crate::{menu::Menu, screen::Screen, theme::widget};
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(
+        OnEnter(Menu::ExtractionSuccess),
+        spawn_extraction_success.after(Summary),
+    );
+}
+
+fn spawn_extraction_success(
+    mut commands: Commands,
+    assets: Res<MenuAssets>,
+    level_assets: Res<LevelAssets>,
+    run_setup: Res<RunSetup>,
+    summary: Res<RunSummary>,
+) {
+    let reward = level_assets
+        .extraction(run_setup.extraction)
+        .map_or(1., |duration| duration.reward);
+    commands.spawn((
+        widget::ui_root("Extraction Success"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::ExtractionSuccess),
+        children![
+            widget::header(&assets, "Extraction success"),
+            widget::label(
+                assets.font.handle.clone(),
+                assets.font_size_base,
+                format!("Extraction reward x{reward}")
+            ),
+            run_summary(&assets, &summary),
+            widget::button(&assets, "Play again", play_again),
+            widget::button(&assets, "Title", quit_to_title),
+        ],
+    ));
+}
+
+fn play_again(
+    _: On<Pointer<Click>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+    mut next: ResMut<NextState<Menu>>,
+    mut run_setup: ResMut<RunSetup>,
+    level_assets: Res<LevelAssets>,
+) {
+    run_setup.roll_seed(&level_assets);
+    next.set(Menu::None);
+    next_screen.set(Screen::Gameplay(true));
+}
+
+fn quit_to_title(_: On<Pointer<Click>>, mut next_screen: ResMut<NextState<Screen>>) {
+    next_screen.set(Screen::Title(true));
+}
@@ -0,0 +1,257 @@
+use crate::gameplay::level::LevelAssets;
+use crate::gameplay::modifier::ModifierAssets;
+use crate::gameplay::run::{RunSetup, random_seed, seed_from_text};
+use crate::menu::{Menu, MenuAssets, Nav};
+use crate::screen::Screen;
+use crate::theme::widget::*;
+use crate::utils::escape_just_pressed;
+use bevy::input_focus::InputFocus;
+use bevy::text::{EditableText, TextEdit, TextEditChange};
+use bevy::{ecs::spawn::SpawnIter, prelude::*};
+
+const SEED_MAX_CHARACTERS: usize = 20;
+const SEED_BACKGROUND: Color = Color::srgb(0.1, 0.1, 0.18);
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::ExtractionTime), spawn_extraction_time_menu);
+    app.add_observer(update_seed);
+    app.add_systems(
+        Update,
+        (
+            go_back.run_if(in_state(Menu::ExtractionTime).and_then(escape_just_pressed)),
+            update_mutator_toggles
+                .run_if(in_state(Menu::ExtractionTime).and_then(resource_changed::<RunSetup>)),
+            restore_seed
+                .run_if(in_state(Menu::ExtractionTime).and_then(resource_changed::<InputFocus>)),
+        ),
+    );
+}
+
+fn spawn_extraction_time_menu(
+    mut commands: Commands,
+    assets: Res<MenuAssets>,
+    level_assets: Res<LevelAssets>,
+    modifier_assets: Res<ModifierAssets>,
+    run_setup: Res<RunSetup>,
+) {
+    let buttons: Vec<_> = level_assets
+        .extraction_durations
+        .iter()
+        .enumerate()
+        .map(|(index, duration)| {
+            button(
+                &assets,
+                format!("{} min - rewards x{}", duration.minutes, duration.reward),
+                move |_: On<Pointer<Click>>,
+                      mut run_setup: ResMut<RunSetup>,
+                      mut next_screen: ResMut<NextState<Screen>>| {
+                    run_setup.extraction = index;
+                    next_screen.set(Screen::Gameplay(true));
+                },
+            )
+        })
+        .collect();
+
+    // The daily seed and modifiers can't be changed.
+    let mutators = match &run_setup.daily {
+        Some(daily) => commands.spawn(label(
+            assets.font.handle.clone(),
+            assets.font_size_base,
+            daily.rules.name.clone(),
+        )),
+        None => commands.spawn(mutator_list(&assets, &modifier_assets, &run_setup)),
+    }
+    .id();
+    let seed = match &run_setup.daily {
+        Some(daily) => commands.spawn(label(
+            assets.font.handle.clone(),
+            assets.font_size_base,
+            format!("Daily {} - seed {}", daily.date, daily.seed),
+        )),
+        None => commands.spawn(seed_field(&assets, run_setup.seed)),
+    }
+    .id();
+
+    commands
+        .spawn((
+            ui_root("Extraction Time Menu"),
+            GlobalZIndex(2),
+            DespawnOnExit(Menu::ExtractionTime),
+            children![
+                header(&assets, "Extraction in"),
+                (
+                    Name::new("Durations"),
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: px(10),
+                        ..default()
+                    },
+                    Children::spawn(SpawnIter(buttons.into_iter())),
+                ),
+                button(&assets, "Back", go_back_on_click),
+            ],
+        ))
+        .insert_children(0, &[seed, mutators]);
+}
+
+/// Toggles the mutator at this index in `ModifierAssets::mutators`.
+#[derive(Component)]
+struct MutatorToggle(usize);
+
+fn mutator_text(name: &str, active: bool) -> String {
+    format!("[{}] {name}", if active { "x" } else { " " })
+}
+
+fn mutator_list(
+    assets: &MenuAssets,
+    modifier_assets: &ModifierAssets,
+    run_setup: &RunSetup,
+) -> impl Bundle {
+    let font = assets.font.handle.clone();
+    let size = assets.font_size_base;
+    let rows: Vec<_> = modifier_assets
+        .mutators
+        .iter()
+        .enumerate()
+        .map(|(index, set)| {
+            let description = set
+                .modifiers
+                .iter()
+                .map(|modifier| modifier.description())
+                .collect::<Vec<_>>()
+                .join(", ");
+            (
+                Name::new(set.name.clone()),
+                Node {
+                    align_items: AlignItems::Center,
+                    column_gap: px(20),
+                    ..default()
+                },
+                children![
+                    (
+                        MutatorToggle(index),
+                        button(
+                            assets,
+                            mutator_text(&set.name, run_setup.mutators.contains(&index)),
+                            move |_: On<Pointer<Click>>, mut run_setup: ResMut<RunSetup>| {
+                                run_setup.toggle_mutator(index);
+                            },
+                        ),
+                    ),
+                    label(font.clone(), size - 6., description),
+                ],
+            )
+        })
+        .collect();
+
+    (
+        Name::new("Mutators"),
+        Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Start,
+            row_gap: px(5),
+            ..default()
+        },
+        Children::spawn(SpawnIter(rows.into_iter())),
+    )
+}
+
+fn update_mutator_toggles(
+    run_setup: Res<RunSetup>,
+    modifier_assets: Res<ModifierAssets>,
+    toggles: Query<(Entity, &MutatorToggle)>,
+    children: Query<&Children>,
+    mut texts: Query<&mut Text>,
+) {
+    for (entity, toggle) in &toggles {
+        let Some(set) = modifier_assets.mutators.get(toggle.0) else {
+            continue;
+        };
+        let active = run_setup.mutators.contains(&toggle.0);
+        for child in children.iter_descendants(entity) {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = mutator_text(&set.name, active);
+            }
+        }
+    }
+}
+
+/// Text input holding the seed of the next run.
+#[derive(Component)]
+struct SeedInput;
+
+fn seed_field(assets: &MenuAssets, seed: u32) -> impl Bundle {
+    (
+        Name::new("Seed Field"),
+        Node {
+            align_items: AlignItems::Center,
+            column_gap: px(20),
+            ..default()
+        },
+        children![
+            label(assets.font.handle.clone(), assets.font_size_base, "Seed"),
+            (
+                Name::new("Seed Input"),
+                SeedInput,
+                Node {
+                    width: px(300),
+                    padding: UiRect::all(px(5)),
+                    ..default()
+                },
+                BackgroundColor(SEED_BACKGROUND),
+                EditableText {
+                    max_characters: Some(SEED_MAX_CHARACTERS),
+                    ..EditableText::new(seed.to_string())
+                },
+                TextFont {
+                    font: FontSource::Handle(assets.font.handle.clone()),
+                    font_size: FontSize::Px(assets.font_size_base),
+                    ..default()
+                },
+            ),
+            button_small(assets, "Random", randomize_seed),
+        ],
+    )
+}
+
+fn update_seed(
+    on: On<TextEditChange>,
+    inputs: Query<&EditableText, With<SeedInput>>,
+    mut run_setup: ResMut<RunSetup>,
+) {
+    let Ok(input) = inputs.get(on.event_target()) else {
+        return;
+    };
+    if let Some(seed) = seed_from_text(&input.value().to_string()) {
+        run_setup.seed = seed;
+    }
+}
+
+/// An emptied field doesn't change the seed, which is shown again once the field loses focus.
+fn restore_seed(
+    input_focus: Res<InputFocus>,
+    run_setup: Res<RunSetup>,
+    mut inputs: Query<(Entity, &mut EditableText), With<SeedInput>>,
+) {
+    for (entity, mut input) in &mut inputs {
+        if input_focus.get() == Some(entity) || !input.value().to_string().trim().is_empty() {
+            continue;
+        }
+        input.clear();
+        input.queue_edit(TextEdit::Insert(run_setup.seed.to_string().into()));
+    }
+}
+
+fn randomize_seed(_: On<Pointer<Click>>, mut input: Single<&mut EditableText, With<SeedInput>>) {
+    input.clear();
+    input.queue_edit(TextEdit::Insert(random_seed().to_string().into()));
+}
+
+fn go_back_on_click(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.trigger(Nav::Back);
+}
+
+fn go_back(mut commands: Commands) {
+    commands.trigger(Nav::Back);
+}
@@ -1,19 +1,23 @@
 use bevy::prelude::*;
 
+use crate::gameplay::level::LevelAssets;
+use crate::gameplay::run::{RunSetup, RunSummary, Summary};
 use crate::menu::MenuAssets;
+use crate::menu::run_summary::run_summary;
 use crate::{menu::Menu, screen::Screen, theme::widget};
 
 pub fn plugin(app: &mut App) {
-    app.add_systems(OnEnter(Menu::GameOver), spawn_game_over);
+    app.add_systems(OnEnter(Menu::GameOver), spawn_game_over.after(Summary));
 }
 
-fn spawn_game_over(mut commands: Commands, assets: Res<MenuAssets>) {
+fn spawn_game_over(mut commands: Commands, assets: Res<MenuAssets>, summary: Res<RunSummary>) {
     commands.spawn((
         widget::ui_root("Game Over"),
         GlobalZIndex(2),
         DespawnOnExit(Menu::GameOver),
         children![
             widget::header(&assets, "Game over"),
+            run_summary(&assets, &summary),
             widget::header(&assets, "Play again ?"),
             widget::button(&assets, "Yes", play_again),
             widget::button(&assets, "No", quit_to_title),
@@ -25,7 +29,10 @@ fn play_again(
     _: On<Pointer<Click>>,
     mut next_screen: ResMut<NextState<Screen>>,
     mut next: ResMut<NextState<Menu>>,
+    mut run_setup: ResMut<RunSetup>,
+    level_assets: Res<LevelAssets>,
 ) {
+    run_setup.roll_seed(&level_assets);
     next.set(Menu::None);
     next_screen.set(Screen::Gameplay(true));
 }
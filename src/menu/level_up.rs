@@ -1,90 +1,98 @@
 use crate::gameplay::health::Health;
-use crate::gameplay::player::weapon::WeaponSlots;
+use crate::gameplay::modifier::RunModifiers;
+use crate::gameplay::player::weapon::{WeaponAssets, WeaponSlots};
 use crate::gameplay::player::{LevelUp, Player, Xp};
+use crate::gameplay::replay::MenuChoice;
+use crate::gameplay::upgrade::Upgrade;
 use crate::menu::{Menu, MenuAssets};
 use crate::theme::widget;
-use bevy::prelude::*;
+use bevy::{ecs::spawn::SpawnIter, prelude::*};
 use widget::{button, header, ui_root};
 
 pub fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Menu::LevelUp), spawn_level_up_menu);
     app.add_observer(open_menu);
+    app.add_observer(choose_upgrade);
 }
 
-fn open_menu(_on: On<LevelUp>, mut next: ResMut<NextState<Menu>>) {
-    next.set(Menu::LevelUp);
+fn open_menu(
+    _on: On<LevelUp>,
+    modifiers: Res<RunModifiers>,
+    mut xp: Single<&mut Xp>,
+    mut next: ResMut<NextState<Menu>>,
+) {
+    if modifiers.level_up_menu {
+        next.set(Menu::LevelUp);
+    } else {
+        xp.level_up();
+    }
 }
 
-fn spawn_level_up_menu(mut commands: Commands, assets: Res<MenuAssets>, xp: Single<&Xp>) {
+fn spawn_level_up_menu(
+    mut commands: Commands,
+    assets: Res<MenuAssets>,
+    weapon_assets: Res<WeaponAssets>,
+    xp: Single<&Xp>,
+    slots: Single<&WeaponSlots>,
+) {
+    let buttons: Vec<_> = Upgrade::offered(&slots, &weapon_assets)
+        .into_iter()
+        .map(|upgrade| upgrade_button(&assets, &weapon_assets, upgrade))
+        .collect();
     commands.spawn((
         ui_root("LevelUp Menu"),
         GlobalZIndex(2),
         DespawnOnExit(Menu::LevelUp),
         children![
             header(&assets, format!("Level up {}", xp.level)),
-            button(&assets, "Damage ++", damage_up),
-            button(&assets, "Bullet speed ++", inc_attack_speed),
-            button(&assets, "Fire rate ++", inc_fire_rate),
-            button(&assets, "Health max ++", inc_health_max),
+            (
+                Name::new("Upgrades"),
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: px(20),
+                    ..default()
+                },
+                Children::spawn(SpawnIter(buttons.into_iter())),
+            ),
             button(&assets, "Skip", skip_menu),
         ],
     ));
 }
 
-fn damage_up(
-    _: On<Pointer<Click>>,
-    mut next: ResMut<NextState<Menu>>,
-    mut xp: Single<&mut Xp>,
-    mut slots: Single<&mut WeaponSlots>,
-) {
-    xp.level_up();
-    let Some(w) = slots.slots.get_mut(0) else {
-        return;
-    };
-    w.inc_damage(0.10);
-    next.set(Menu::None);
+fn upgrade_button(
+    assets: &MenuAssets,
+    weapon_assets: &WeaponAssets,
+    upgrade: Upgrade,
+) -> impl Bundle {
+    button(
+        assets,
+        upgrade.label(weapon_assets),
+        move |_: On<Pointer<Click>>, mut commands: Commands| {
+            commands.trigger(MenuChoice::Upgrade(upgrade));
+        },
+    )
 }
 
-fn inc_attack_speed(
-    _: On<Pointer<Click>>,
-    mut next: ResMut<NextState<Menu>>,
-    mut xp: Single<&mut Xp>,
-    mut slots: Single<&mut WeaponSlots>,
-) {
-    xp.level_up();
-    let Some(w) = slots.slots.get_mut(0) else {
-        return;
-    };
-    w.inc_speed(0.10);
-    next.set(Menu::None);
+fn skip_menu(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.trigger(MenuChoice::SkipUpgrade);
 }
 
-fn inc_fire_rate(
-    _: On<Pointer<Click>>,
+fn choose_upgrade(
+    choice: On<MenuChoice>,
     mut next: ResMut<NextState<Menu>>,
     mut xp: Single<&mut Xp>,
     mut slots: Single<&mut WeaponSlots>,
-) {
-    xp.level_up();
-    let Some(w) = slots.slots.get_mut(0) else {
-        return;
-    };
-    w.inc_fire_rate(0.50);
-    next.set(Menu::None);
-}
-
-fn inc_health_max(
-    _: On<Pointer<Click>>,
-    mut next: ResMut<NextState<Menu>>,
-    mut xp: Single<&mut Xp>,
     mut health: Single<&mut Health, With<Player>>,
+    weapon_assets: Res<WeaponAssets>,
 ) {
-    xp.level_up();
-    health.inc_max_health(0.10);
-    next.set(Menu::None);
-}
-
-fn skip_menu(_: On<Pointer<Click>>, mut next: ResMut<NextState<Menu>>, mut xp: Single<&mut Xp>) {
-    xp.skip();
+    match *choice {
+        MenuChoice::Upgrade(upgrade) => {
+            xp.level_up();
+            upgrade.apply(&mut slots, &mut health, &weapon_assets);
+        }
+        MenuChoice::SkipUpgrade => xp.skip(),
+        MenuChoice::CollectChest | MenuChoice::RerollChest => return,
+    }
     next.set(Menu::None);
 }
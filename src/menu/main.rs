@@ -1,11 +1,12 @@
 use bevy::prelude::*;
 
 use crate::audio::{AudioSettings, music};
+use crate::gameplay::level::LevelAssets;
+use crate::gameplay::run::RunSetup;
 use crate::menu::MenuAssets;
 use crate::{
     MetaState,
     menu::{Menu, Nav},
-    screen::Screen,
     theme::widget,
 };
 
@@ -21,22 +22,48 @@ fn spawn_main_menu(assets: Res<MenuAssets>, mut commands: Commands) {
         DespawnOnExit(Menu::Main),
         #[cfg(not(target_family = "wasm"))]
         children![
-            widget::button(&assets, "Play", enter_gameplay_screen),
+            widget::button(&assets, "Play", open_character_select_menu),
+            widget::button(&assets, "Daily", open_daily_menu),
+            widget::button(&assets, "Shop", open_shop_menu),
+            widget::button(&assets, "Stats", open_stats_menu),
             widget::button(&assets, "Settings", open_settings_menu),
             widget::button(&assets, "Credits", open_credits_menu),
             widget::button(&assets, "Exit", exit_app),
         ],
         #[cfg(target_family = "wasm")]
         children![
-            widget::button(&assets, "Play", enter_gameplay_screen),
+            widget::button(&assets, "Play", open_character_select_menu),
+            widget::button(&assets, "Shop", open_shop_menu),
+            widget::button(&assets, "Stats", open_stats_menu),
             widget::button(&assets, "Settings", open_settings_menu),
             widget::button(&assets, "Credits", open_credits_menu),
         ],
     ));
 }
 
-fn enter_gameplay_screen(_: On<Pointer<Click>>, mut next_screen: ResMut<NextState<Screen>>) {
-    next_screen.set(Screen::Gameplay(true));
+fn open_character_select_menu(
+    _: On<Pointer<Click>>,
+    mut commands: Commands,
+    mut run_setup: ResMut<RunSetup>,
+    level_assets: Res<LevelAssets>,
+) {
+    run_setup.daily = None;
+    run_setup.roll_seed(&level_assets);
+    commands.trigger(Nav::Open(Menu::CharacterSelect));
+}
+
+/// The date is unknown on the web, and results can't be saved there.
+#[cfg(not(target_family = "wasm"))]
+fn open_daily_menu(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.trigger(Nav::Open(Menu::Daily));
+}
+
+fn open_shop_menu(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.trigger(Nav::Open(Menu::Shop));
+}
+
+fn open_stats_menu(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.trigger(Nav::Open(Menu::Stats));
 }
 
 fn open_settings_menu(_: On<Pointer<Click>>, mut commands: Commands) {
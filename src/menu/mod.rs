@@ -1,9 +1,17 @@
+mod character_select;
+mod chest;
 mod credits;
+mod daily;
+mod extraction_success;
+mod extraction_time;
 mod game_over;
 mod level_up;
 mod main;
 mod pause;
+mod run_summary;
 mod settings;
+mod shop;
+mod stats;
 
 use crate::asset_tracking::LoadResource;
 use bevy::prelude::*;
@@ -20,12 +28,21 @@ pub fn plugin(app: &mut App) {
 
     app.add_systems(OnEnter(Menu::Pause), spawn_overlay);
     app.add_systems(OnEnter(Menu::LevelUp), spawn_overlay);
+    app.add_systems(OnEnter(Menu::Chest), spawn_overlay);
     app.add_systems(OnEnter(Menu::GameOver), spawn_overlay);
+    app.add_systems(OnEnter(Menu::ExtractionSuccess), spawn_overlay);
 
     app.add_plugins((
+        character_select::plugin,
+        chest::plugin,
         credits::plugin,
+        daily::plugin,
+        extraction_time::plugin,
+        extraction_success::plugin,
         main::plugin,
         settings::plugin,
+        shop::plugin,
+        stats::plugin,
         pause::plugin,
         level_up::plugin,
         game_over::plugin,
@@ -37,11 +54,18 @@ pub enum Menu {
     #[default]
     None,
     Main,
+    CharacterSelect,
+    Daily,
+    ExtractionTime,
     Credits,
     Settings,
+    Shop,
+    Stats,
     Pause,
     LevelUp,
+    Chest,
     GameOver,
+    ExtractionSuccess,
 }
 
 #[derive(Event, Copy, Clone)]
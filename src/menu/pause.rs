@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 
+use crate::gameplay::run::RunSetup;
 use crate::menu::MenuAssets;
 use crate::utils::escape_just_pressed;
 use crate::{
@@ -16,13 +17,18 @@ pub fn plugin(app: &mut App) {
     );
 }
 
-fn spawn_pause_menu(assets: Res<MenuAssets>, mut commands: Commands) {
+fn spawn_pause_menu(assets: Res<MenuAssets>, run_setup: Res<RunSetup>, mut commands: Commands) {
     commands.spawn((
         widget::ui_root("Pause Menu"),
         GlobalZIndex(2),
         DespawnOnExit(Menu::Pause),
         children![
             widget::header(&assets, "Game paused"),
+            widget::label(
+                assets.font.handle.clone(),
+                assets.font_size_base,
+                format!("Seed {}", run_setup.seed)
+            ),
             widget::button(&assets, "Continue", close_menu),
             widget::button(&assets, "Settings", open_settings_menu),
             widget::button(&assets, "Quit to title", quit_to_title),
@@ -0,0 +1,81 @@
+use crate::gameplay::run::RunSummary;
+use crate::menu::MenuAssets;
+use crate::theme::widget::label;
+use bevy::{ecs::spawn::SpawnIter, prelude::*};
+
+/// Two columns of stats shared by the game over and extraction screens.
+pub fn run_summary(assets: &MenuAssets, summary: &RunSummary) -> impl Bundle {
+    let secs = summary.time_survived as u32;
+    let mut rows = vec![
+        (
+            "Survived".to_string(),
+            format!("{:02}:{:02}", secs / 60, secs % 60),
+        ),
+        ("Kills".to_string(), summary.kills.to_string()),
+        ("Level".to_string(), summary.level.to_string()),
+    ];
+    rows.extend(
+        summary
+            .damage_dealt
+            .iter()
+            .map(|(weapon, damage)| (format!("Damage {weapon}"), format!("{damage:.0}"))),
+    );
+    rows.extend([
+        (
+            "Damage taken".to_string(),
+            format!("{:.0}", summary.damage_taken),
+        ),
+        ("XP".to_string(), format!("{:.0}", summary.xp_collected)),
+        ("Credits".to_string(), summary.credits.to_string()),
+        (
+            "Net".to_string(),
+            format!("{:+} (clone {})", summary.net(), summary.clone_cost),
+        ),
+        ("Seed".to_string(), summary.seed.to_string()),
+    ]);
+    if !summary.modifiers.is_empty() {
+        rows.push(("Modifiers".to_string(), summary.modifiers.join(", ")));
+    }
+
+    stat_grid(assets, rows)
+}
+
+/// Names right aligned against their values.
+pub fn stat_grid(assets: &MenuAssets, rows: Vec<(String, String)>) -> impl Bundle {
+    let font = assets.font.handle.clone();
+    let size = assets.font_size_base - 6.;
+    let cells = rows.into_iter().flat_map(move |(name, value)| {
+        [
+            (
+                label(font.clone(), size, name),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                },
+            ),
+            (
+                label(font.clone(), size, value),
+                Node {
+                    justify_self: JustifySelf::Start,
+                    ..default()
+                },
+            ),
+        ]
+    });
+    (
+        Name::new("Stat Grid"),
+        Node {
+            display: Display::Grid,
+            row_gap: px(4),
+            column_gap: px(30),
+            grid_template_columns: RepeatedGridTrack::px(2, 300.0),
+            ..default()
+        },
+        Children::spawn(SpawnIter(cells)),
+    )
+}
+
+pub fn format_time(secs: f32) -> String {
+    let secs = secs as u32;
+    format!("{:02}:{:02}", secs / 60, secs % 60)
+}
@@ -0,0 +1,180 @@
+use crate::menu::{Menu, MenuAssets, Nav};
+use crate::profile::Profile;
+use crate::profile::shop::{MetaUpgrade, ShopAssets};
+use crate::theme::widget::*;
+use crate::utils::escape_just_pressed;
+use bevy::{ecs::spawn::SpawnIter, prelude::*};
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::Shop), spawn_shop_menu);
+    app.add_systems(
+        Update,
+        (
+            update_shop_labels.run_if(resource_changed::<Profile>),
+            go_back.run_if(escape_just_pressed),
+        )
+            .run_if(in_state(Menu::Shop)),
+    );
+}
+
+/// Label showing the credits left.
+#[derive(Component)]
+struct CreditsLabel;
+
+/// Label showing the tier bought of the shop upgrade at this index.
+#[derive(Component)]
+struct TierLabel(usize);
+
+/// Button buying the next tier of the shop upgrade at this index.
+#[derive(Component)]
+struct BuyButton(usize);
+
+fn spawn_shop_menu(
+    mut commands: Commands,
+    assets: Res<MenuAssets>,
+    shop_assets: Res<ShopAssets>,
+    profile: Res<Profile>,
+) {
+    commands.spawn(shop_menu(&assets, &shop_assets, &profile));
+}
+
+fn update_shop_labels(
+    shop_assets: Res<ShopAssets>,
+    profile: Res<Profile>,
+    credits: Query<Entity, With<CreditsLabel>>,
+    tiers: Query<(Entity, &TierLabel)>,
+    buttons: Query<(Entity, &BuyButton)>,
+    children: Query<&Children>,
+    mut texts: Query<&mut Text>,
+) {
+    for entity in &credits {
+        if let Ok(mut text) = texts.get_mut(entity) {
+            text.0 = credits_text(&profile);
+        }
+    }
+    for (entity, tier) in &tiers {
+        if let (Some(upgrade), Ok(mut text)) =
+            (shop_assets.upgrades.get(tier.0), texts.get_mut(entity))
+        {
+            text.0 = tier_text(upgrade, &profile);
+        }
+    }
+    for (entity, button) in &buttons {
+        let Some(upgrade) = shop_assets.upgrades.get(button.0) else {
+            continue;
+        };
+        for child in children.iter_descendants(entity) {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = buy_text(upgrade, &profile);
+            }
+        }
+    }
+}
+
+fn credits_text(profile: &Profile) -> String {
+    format!("Credits: {}", profile.credits)
+}
+
+fn tier_text(upgrade: &MetaUpgrade, profile: &Profile) -> String {
+    format!("{}/{}", upgrade.tier(profile), upgrade.prices.len())
+}
+
+fn buy_text(upgrade: &MetaUpgrade, profile: &Profile) -> String {
+    upgrade
+        .next_price(profile)
+        .map_or("Max".to_string(), |price| format!("Buy {price}"))
+}
+
+fn shop_menu(assets: &MenuAssets, shop_assets: &ShopAssets, profile: &Profile) -> impl Bundle {
+    let rows: Vec<_> = shop_assets
+        .upgrades
+        .iter()
+        .enumerate()
+        .map(|(index, upgrade)| upgrade_row(assets, index, upgrade, profile))
+        .collect();
+
+    (
+        ui_root("Shop Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::Shop),
+        children![
+            header(assets, "Shop"),
+            (
+                CreditsLabel,
+                label(
+                    assets.font.handle.clone(),
+                    assets.font_size_base,
+                    credits_text(profile)
+                ),
+            ),
+            (
+                Name::new("Upgrades"),
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: px(10),
+                    ..default()
+                },
+                Children::spawn(SpawnIter(rows.into_iter())),
+            ),
+            button(assets, "Back", go_back_on_click),
+        ],
+    )
+}
+
+fn upgrade_row(
+    assets: &MenuAssets,
+    index: usize,
+    upgrade: &MetaUpgrade,
+    profile: &Profile,
+) -> impl Bundle {
+    let font = assets.font.handle.clone();
+    let size = assets.font_size_base - 6.;
+    (
+        Name::new("Upgrade Row"),
+        Node {
+            align_items: AlignItems::Center,
+            column_gap: px(20),
+            ..default()
+        },
+        children![
+            label(font.clone(), size, upgrade.name.clone()),
+            label(font.clone(), size, upgrade.effect.description()),
+            (
+                TierLabel(index),
+                label(font, size, tier_text(upgrade, profile)),
+            ),
+            (
+                BuyButton(index),
+                button_small(
+                    assets,
+                    buy_text(upgrade, profile),
+                    move |_: On<Pointer<Click>>,
+                          shop_assets: Res<ShopAssets>,
+                          mut profile: ResMut<Profile>| {
+                        buy_upgrade(&shop_assets.upgrades[index], &mut profile);
+                    }
+                ),
+            ),
+        ],
+    )
+}
+
+fn buy_upgrade(upgrade: &MetaUpgrade, profile: &mut Profile) {
+    let Some(price) = upgrade.next_price(profile) else {
+        return;
+    };
+    let Some(credits) = profile.credits.checked_sub(price) else {
+        return;
+    };
+    profile.credits = credits;
+    *profile.upgrades.entry(upgrade.name.clone()).or_default() += 1;
+    profile.save();
+}
+
+fn go_back_on_click(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.trigger(Nav::Back);
+}
+
+fn go_back(mut commands: Commands) {
+    commands.trigger(Nav::Back);
+}
@@ -0,0 +1,207 @@
+use crate::gameplay::replay::{Recording, Replay};
+use crate::gameplay::run::{RunEnd, RunSetup, RunSummary};
+use crate::menu::run_summary::{format_time, stat_grid};
+use crate::menu::{Menu, MenuAssets, Nav};
+use crate::profile::{Profile, history};
+use crate::screen::Screen;
+use crate::theme::widget::*;
+use crate::utils::escape_just_pressed;
+use bevy::{ecs::spawn::SpawnIter, prelude::*};
+use std::collections::BTreeMap;
+
+/// Number of previous runs listed with their seed.
+const LAST_RUNS: usize = 5;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::Stats), spawn_stats_menu);
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::Stats).and_then(escape_just_pressed)),
+    );
+}
+
+fn spawn_stats_menu(mut commands: Commands, assets: Res<MenuAssets>, profile: Res<Profile>) {
+    let history = history::load();
+    let font = assets.font.handle.clone();
+    let size = assets.font_size_base;
+
+    let last_runs: Vec<_> = history
+        .iter()
+        .rev()
+        .take(LAST_RUNS)
+        .map(|run| last_run(&assets, run))
+        .collect();
+
+    commands.spawn((
+        ui_root("Stats Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::Stats),
+        children![
+            header(&assets, "Stats"),
+            (
+                Name::new("Columns"),
+                Node {
+                    column_gap: px(60),
+                    ..default()
+                },
+                children![
+                    (
+                        Name::new("Records"),
+                        Node {
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            row_gap: px(10),
+                            ..default()
+                        },
+                        children![
+                            label(font.clone(), size, "Records"),
+                            stat_grid(&assets, records(&profile)),
+                            label(font.clone(), size, "Characters"),
+                            stat_grid(&assets, by_character(&history)),
+                            label(font.clone(), size, "Weapons"),
+                            stat_grid(&assets, by_weapon(&history)),
+                        ],
+                    ),
+                    (
+                        Name::new("Last Runs"),
+                        Node {
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            row_gap: px(10),
+                            ..default()
+                        },
+                        Children::spawn((
+                            Spawn(label(font, size, "Last runs")),
+                            SpawnIter(last_runs.into_iter()),
+                        )),
+                    ),
+                ],
+            ),
+            watch_button(&assets, Recording::load().is_some()),
+            button(&assets, "Back", go_back_on_click),
+        ],
+    ));
+}
+
+fn records(profile: &Profile) -> Vec<(String, String)> {
+    let stats = &profile.stats;
+    vec![
+        ("Runs".to_string(), stats.runs.to_string()),
+        ("Extractions".to_string(), stats.extractions.to_string()),
+        ("Deaths".to_string(), stats.deaths.to_string()),
+        ("Kills".to_string(), stats.kills.to_string()),
+        ("Time played".to_string(), format_time(stats.time_survived)),
+        ("Best time".to_string(), format_time(stats.best_time)),
+        ("Most kills".to_string(), stats.most_kills.to_string()),
+    ]
+}
+
+fn by_character(history: &[RunSummary]) -> Vec<(String, String)> {
+    let mut characters = BTreeMap::<&str, (u32, f32, u32)>::new();
+    for run in history.iter().filter(|run| !run.character.is_empty()) {
+        let (runs, best_time, kills) = characters.entry(&run.character).or_default();
+        *runs += 1;
+        *best_time = best_time.max(run.time_survived);
+        *kills += run.kills;
+    }
+    characters
+        .into_iter()
+        .map(|(name, (runs, best_time, kills))| {
+            (
+                name.to_string(),
+                format!(
+                    "{runs} runs, best {}, {kills} kills",
+                    format_time(best_time)
+                ),
+            )
+        })
+        .collect()
+}
+
+fn by_weapon(history: &[RunSummary]) -> Vec<(String, String)> {
+    let mut weapons = BTreeMap::<&str, (u32, f32)>::new();
+    for run in history {
+        for (name, damage) in &run.damage_dealt {
+            let (runs, total) = weapons.entry(name).or_default();
+            *runs += 1;
+            *total += damage;
+        }
+    }
+    weapons
+        .into_iter()
+        .map(|(name, (runs, damage))| {
+            (name.to_string(), format!("{runs} runs, {damage:.0} damage"))
+        })
+        .collect()
+}
+
+fn last_run(assets: &MenuAssets, run: &RunSummary) -> impl Bundle {
+    let end = match run.end {
+        RunEnd::Death => "Died",
+        RunEnd::Extraction => "Extracted",
+    };
+    let seed = run.seed;
+    (
+        Name::new("Last Run"),
+        Node {
+            align_items: AlignItems::Center,
+            column_gap: px(20),
+            ..default()
+        },
+        children![
+            label(
+                assets.font.handle.clone(),
+                assets.font_size_base - 6.,
+                format!(
+                    "{end} {} - {} kills - seed {seed}",
+                    format_time(run.time_survived),
+                    run.kills
+                )
+            ),
+            button_small(
+                assets,
+                "Replay",
+                move |_: On<Pointer<Click>>,
+                      mut run_setup: ResMut<RunSetup>,
+                      mut commands: Commands| {
+                    run_setup.seed = seed;
+                    run_setup.daily = None;
+                    run_setup.mutators.clear();
+                    commands.trigger(Nav::Open(Menu::CharacterSelect));
+                }
+            ),
+        ],
+    )
+}
+
+fn watch_button(assets: &MenuAssets, available: bool) -> impl Bundle {
+    (
+        button(assets, "Watch last run", watch_last_run),
+        if available {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        },
+    )
+}
+
+fn watch_last_run(
+    _: On<Pointer<Click>>,
+    mut commands: Commands,
+    mut run_setup: ResMut<RunSetup>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    let Some(recording) = Recording::load() else {
+        return;
+    };
+    commands.insert_resource(Replay::start(recording, &mut run_setup));
+    next_screen.set(Screen::Gameplay(true));
+}
+
+fn go_back_on_click(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.trigger(Nav::Back);
+}
+
+fn go_back(mut commands: Commands) {
+    commands.trigger(Nav::Back);
+}
@@ -0,0 +1,18 @@
+use crate::gameplay::run::RunSummary;
+use crate::profile::storage;
+
+const HISTORY_FILE: &str = "history.ron";
+/// Oldest runs are dropped past this count.
+const HISTORY_LIMIT: usize = 100;
+
+pub fn load() -> Vec<RunSummary> {
+    storage::load_or_back_up(HISTORY_FILE).unwrap_or_default()
+}
+
+pub fn append(summary: RunSummary) {
+    let mut history = load();
+    history.push(summary);
+    let overflow = history.len().saturating_sub(HISTORY_LIMIT);
+    history.drain(..overflow);
+    storage::save(HISTORY_FILE, &history);
+}
@@ -0,0 +1,139 @@
+use crate::gameplay::replay::Replay;
+use crate::gameplay::run::{RunEnd, RunSummary, Summary};
+use crate::menu::Menu;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub mod history;
+pub mod shop;
+pub mod storage;
+
+const PROFILE_FILE: &str = "profile.ron";
+const PROFILE_VERSION: u32 = 2;
+
+pub fn plugin(app: &mut App) {
+    app.insert_resource(Profile::load());
+    app.add_plugins(shop::plugin);
+    app.add_systems(
+        OnEnter(Menu::GameOver),
+        record_run
+            .after(Summary)
+            .run_if(not(resource_exists::<Replay>)),
+    );
+    app.add_systems(
+        OnEnter(Menu::ExtractionSuccess),
+        record_run
+            .after(Summary)
+            .run_if(not(resource_exists::<Replay>)),
+    );
+}
+
+/// Everything kept between runs.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct Profile {
+    /// Missing in files written before versioning, hence 0.
+    #[serde(default)]
+    pub version: u32,
+    /// Banked meta currency.
+    pub credits: u32,
+    /// Tier purchased for each meta upgrade, by name.
+    pub upgrades: HashMap<String, u32>,
+    pub stats: LifetimeStats,
+    /// Dates of the dailies started, only the first attempt of a day is scored.
+    pub daily_attempts: Vec<String>,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self {
+            version: PROFILE_VERSION,
+            credits: 0,
+            upgrades: HashMap::new(),
+            stats: LifetimeStats::default(),
+            daily_attempts: vec![],
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct LifetimeStats {
+    pub runs: u32,
+    pub deaths: u32,
+    pub extractions: u32,
+    pub kills: u32,
+    /// In seconds.
+    pub time_survived: f32,
+    pub credits_earned: u32,
+    /// Longest run, in seconds.
+    pub best_time: f32,
+    /// Most kills in a single run.
+    pub most_kills: u32,
+}
+
+impl LifetimeStats {
+    /// Keeps the run if it beats a record.
+    fn record(&mut self, run: &RunSummary) {
+        self.best_time = self.best_time.max(run.time_survived);
+        self.most_kills = self.most_kills.max(run.kills);
+    }
+}
+
+impl Profile {
+    fn load() -> Self {
+        storage::load_or_back_up::<Profile>(PROFILE_FILE)
+            .map(Profile::migrate)
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        storage::save(PROFILE_FILE, self);
+    }
+
+    fn migrate(mut self) -> Self {
+        if self.version < 1 {
+            // Version 0 only lacked the version field.
+            self.version = 1;
+        }
+        if self.version < 2 {
+            // Records were read from the history, which only keeps the last runs.
+            for run in history::load() {
+                self.stats.record(&run);
+            }
+            self.version = 2;
+        }
+        self
+    }
+}
+
+fn record_run(mut profile: ResMut<Profile>, summary: Res<RunSummary>) {
+    profile.credits += summary.credits;
+    let stats = &mut profile.stats;
+    stats.runs += 1;
+    match summary.end {
+        RunEnd::Death => stats.deaths += 1,
+        RunEnd::Extraction => stats.extractions += 1,
+    }
+    stats.kills += summary.kills;
+    stats.time_survived += summary.time_survived;
+    stats.credits_earned += summary.credits;
+    stats.record(&summary);
+    profile.save();
+    history::append(summary.clone());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrate_unversioned_profile() {
+        let profile: Profile = ron::from_str("(credits: 12)").unwrap();
+        assert_eq!(profile.version, 0);
+        let profile = profile.migrate();
+        assert_eq!(profile.version, PROFILE_VERSION);
+        assert_eq!(profile.credits, 12);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::asset_tracking::LoadResource;
+use crate::profile::Profile;
+use bevy::prelude::*;
+use ron_asset_manager::prelude::RonAsset;
+use serde::{Deserialize, Serialize};
+
+pub fn plugin(app: &mut App) {
+    app.load_resource::<ShopAssets>("shop.ron");
+}
+
+#[derive(Resource, Asset, RonAsset, TypePath, Deserialize, Debug, Clone)]
+pub struct ShopAssets {
+    pub upgrades: Vec<MetaUpgrade>,
+}
+
+/// Permanent upgrade bought with banked credits.
+#[derive(Deserialize, Debug, Clone)]
+pub struct MetaUpgrade {
+    /// Also the key of the purchased tier in the profile.
+    pub name: String,
+    pub effect: MetaEffect,
+    /// Price of each tier, the effect is granted once per purchased tier.
+    pub prices: Vec<u32>,
+}
+
+#[derive(Deserialize, Debug, Copy, Clone)]
+pub enum MetaEffect {
+    MaxHealth(f32),
+    WeaponSlot(usize),
+    Reroll(u32),
+    /// Share of the clone cost removed.
+    CloneCost(f32),
+}
+
+impl MetaEffect {
+    pub fn description(&self) -> String {
+        match self {
+            MetaEffect::MaxHealth(amount) => format!("+{amount} starting HP"),
+            MetaEffect::WeaponSlot(count) => format!("+{count} weapon slot"),
+            MetaEffect::Reroll(count) => format!("+{count} reroll token"),
+            MetaEffect::CloneCost(ratio) => format!("-{}% clone cost", (ratio * 100.).round()),
+        }
+    }
+}
+
+impl MetaUpgrade {
+    pub fn tier(&self, profile: &Profile) -> usize {
+        profile
+            .upgrades
+            .get(&self.name)
+            .map_or(0, |tier| *tier as usize)
+            .min(self.prices.len())
+    }
+
+    /// Price of the next tier, `None` once maxed out.
+    pub fn next_price(&self, profile: &Profile) -> Option<u32> {
+        self.prices.get(self.tier(profile)).copied()
+    }
+}
+
+/// Sum of the purchased upgrades, applied when a run starts.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy)]
+pub struct MetaBonuses {
+    pub max_health: f32,
+    pub weapon_slots: usize,
+    pub rerolls: u32,
+    pub clone_cost_reduction: f32,
+}
+
+impl ShopAssets {
+    pub fn bonuses(&self, profile: &Profile) -> MetaBonuses {
+        let mut bonuses = MetaBonuses::default();
+        for upgrade in &self.upgrades {
+            let tier = upgrade.tier(profile);
+            match upgrade.effect {
+                MetaEffect::MaxHealth(amount) => bonuses.max_health += amount * tier as f32,
+                MetaEffect::WeaponSlot(count) => bonuses.weapon_slots += count * tier,
+                MetaEffect::Reroll(count) => bonuses.rerolls += count * tier as u32,
+                MetaEffect::CloneCost(ratio) => {
+                    bonuses.clone_cost_reduction += ratio * tier as f32;
+                }
+            }
+        }
+        bonuses
+    }
+}
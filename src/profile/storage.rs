@@ -0,0 +1,108 @@
+//! Small RON files kept in the platform data directory. Nothing is stored on the web, nor by
+//! tests.
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::path::PathBuf;
+
+#[cfg(not(any(target_family = "wasm", test)))]
+const APP_DIR: &str = "colonie-terminus";
+
+/// Platform data directory of the game, `None` when it can't be found.
+#[cfg(not(any(target_family = "wasm", test)))]
+pub fn data_dir() -> Option<PathBuf> {
+    use std::env::var_os;
+
+    let base = if cfg!(target_os = "windows") {
+        var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        var_os("HOME").map(|home| PathBuf::from(home).join("Library/Application Support"))
+    } else {
+        var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+    };
+    base.map(|base| base.join(APP_DIR))
+}
+
+#[cfg(any(target_family = "wasm", test))]
+pub fn data_dir() -> Option<PathBuf> {
+    None
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LoadError {
+    /// Never saved, or no data directory.
+    Missing,
+    /// Present but unreadable, saving over it would lose its content.
+    Corrupt,
+}
+
+/// Reads `file` from the data directory.
+pub fn load<T: DeserializeOwned>(file: &str) -> Result<T, LoadError> {
+    let path = data_dir().ok_or(LoadError::Missing)?.join(file);
+    let content = std::fs::read_to_string(&path).map_err(|error| match error.kind() {
+        std::io::ErrorKind::NotFound => LoadError::Missing,
+        _ => {
+            bevy::log::error!("Could not read {}: {error}", path.display());
+            LoadError::Corrupt
+        }
+    })?;
+    ron::from_str(&content).map_err(|error| {
+        bevy::log::error!("Could not read {}: {error}", path.display());
+        LoadError::Corrupt
+    })
+}
+
+/// Same as `load`, a corrupt file is first moved to `<file>.bak` so the next save keeps it.
+pub fn load_or_back_up<T: DeserializeOwned>(file: &str) -> Option<T> {
+    match load(file) {
+        Ok(value) => Some(value),
+        Err(LoadError::Missing) => None,
+        Err(LoadError::Corrupt) => {
+            back_up(file);
+            None
+        }
+    }
+}
+
+fn back_up(file: &str) {
+    let Some(dir) = data_dir() else {
+        return;
+    };
+    let backup = format!("{file}.bak");
+    match std::fs::rename(dir.join(file), dir.join(&backup)) {
+        Ok(()) => bevy::log::warn!("Moved the unreadable {file} to {backup}"),
+        Err(error) => bevy::log::error!("Could not back up {file}: {error}"),
+    }
+}
+
+/// Writes `file` in the data directory through a temporary file, so a crash never leaves it
+/// half written.
+pub fn save<T: Serialize>(file: &str, value: &T) {
+    write(file, || {
+        ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+    });
+}
+
+/// Same as `save` on a single line, for files too long to be read by hand.
+pub fn save_compact<T: Serialize>(file: &str, value: &T) {
+    write(file, || ron::ser::to_string(value));
+}
+
+fn write(file: &str, serialize: impl FnOnce() -> ron::Result<String>) {
+    let Some(dir) = data_dir() else {
+        return;
+    };
+    let result = (|| -> Result<(), Box<dyn std::error::Error>> {
+        std::fs::create_dir_all(&dir)?;
+        let content = serialize()?;
+        let tmp = dir.join(format!("{file}.tmp"));
+        std::fs::write(&tmp, content)?;
+        std::fs::rename(&tmp, dir.join(file))?;
+        Ok(())
+    })();
+    if let Err(error) = result {
+        bevy::log::error!("Could not save {file}: {error}");
+    }
+}
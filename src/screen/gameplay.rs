@@ -1,11 +1,11 @@
 use avian2d::prelude::{Physics, PhysicsTime};
 use bevy::prelude::*;
 
-use crate::MetaState;
 use crate::audio::{AudioSettings, music};
 use crate::gameplay::level::{GameplayMusic, LevelAssets};
 use crate::menu::Menu;
 use crate::utils::escape_just_pressed;
+use crate::{MetaState, menu_closed};
 
 pub fn plugin(app: &mut App) {
     app.add_systems(
@@ -26,6 +26,15 @@ pub fn plugin(app: &mut App) {
         OnExit(Menu::None),
         stop_simulation.run_if(in_state(MetaState::InGame)),
     );
+    // A menu opened by the simulation also stops the physics of the ticks left in the frame.
+    app.add_systems(
+        FixedUpdate,
+        stop_simulation.run_if(
+            in_state(MetaState::InGame)
+                .and_then(in_state(Menu::None))
+                .and_then(not(menu_closed)),
+        ),
+    );
     app.add_systems(OnEnter(Menu::GameOver), start_game_over_music);
 }
 
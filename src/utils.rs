@@ -1,4 +1,7 @@
-use bevy::prelude::{ButtonInput, Gamepad, GamepadButton, KeyCode, Query, Res};
+use bevy::prelude::{
+    ButtonInput, Camera, Gamepad, GamepadButton, GlobalTransform, KeyCode, Query, Rect, Res, Vec2,
+    Window,
+};
 use itertools::Itertools;
 
 pub fn escape_just_pressed(keyboard: Res<ButtonInput<KeyCode>>, gamepads: Query<&Gamepad>) -> bool {
@@ -24,3 +27,19 @@ pub fn group_by<I, C: Ord, S: Ord>(
         .map(|(_, chunk)| chunk.sorted_by_key(|item| sorter(item)).collect())
         .collect()
 }
+
+/// Part of the world seen by the camera.
+pub fn camera_world_rect(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    window: &Window,
+) -> Option<Rect> {
+    let resolution = Vec2::new(window.resolution.width(), window.resolution.height());
+    let top_left = camera
+        .viewport_to_world_2d(camera_transform, Vec2::ZERO)
+        .ok()?;
+    let bottom_right = camera
+        .viewport_to_world_2d(camera_transform, resolution)
+        .ok()?;
+    Some(Rect::from_corners(top_left, bottom_right))
+}